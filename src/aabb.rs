@@ -0,0 +1,97 @@
+use crate::Vector3D;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+    pub min: Vector3D,
+    pub max: Vector3D,
+}
+
+impl Aabb {
+    /// Negative margins that would invert an axis collapse that axis to the box center.
+    pub fn expanded(&self, margin: f64) -> Aabb {
+        let center = (self.min + self.max) * 0.5;
+        let half = (self.max - self.min) * 0.5;
+        let half = Vector3D::new((half.x + margin).max(0.0), (half.y + margin).max(0.0), (half.z + margin).max(0.0));
+        Aabb::new(center - half, center + half)
+    }
+
+    pub fn intersection(&self, other: &Aabb) -> Option<Aabb> {
+        let min = Vector3D::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y), self.min.z.max(other.min.z));
+        let max = Vector3D::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y), self.max.z.min(other.max.z));
+        if min.x > max.x || min.y > max.y || min.z > max.z {
+            return None;
+        }
+        Some(Aabb::new(min, max))
+    }
+
+    pub fn new(min: Vector3D, max: Vector3D) -> Self {
+        Self { min, max }
+    }
+
+    pub fn signed_distance(&self, p: Vector3D) -> f64 {
+        let center = (self.min + self.max) * 0.5;
+        let half = (self.max - self.min) * 0.5;
+        let d = Vector3D::new(
+            (p.x - center.x).abs() - half.x,
+            (p.y - center.y).abs() - half.y,
+            (p.z - center.z).abs() - half.z);
+        let outside = Vector3D::new(d.x.max(0.0), d.y.max(0.0), d.z.max(0.0)).magnitude();
+        let inside = d.x.max(d.y).max(d.z).min(0.0);
+        outside + inside
+    }
+
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        let min = Vector3D::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y), self.min.z.min(other.min.z));
+        let max = Vector3D::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y), self.max.z.max(other.max.z));
+        Aabb::new(min, max)
+    }
+}
+
+#[cfg(test)]
+mod aabb_tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn expanded() {
+        let aabb = Aabb::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(2.0, 4.0, 6.0));
+        assert_eq!(aabb.expanded(1.0), Aabb::new(Vector3D::new(-1.0, -1.0, -1.0), Vector3D::new(3.0, 5.0, 7.0)));
+        assert_eq!(aabb.expanded(-1.5), Aabb::new(Vector3D::new(1.0, 1.5, 1.5), Vector3D::new(1.0, 2.5, 4.5)));
+    }
+
+    #[test]
+    fn intersection() {
+        let a = Aabb::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(2.0, 2.0, 2.0));
+        let overlapping = Aabb::new(Vector3D::new(1.0, -1.0, 1.0), Vector3D::new(3.0, 1.0, 3.0));
+        assert_eq!(a.intersection(&overlapping),
+            Some(Aabb::new(Vector3D::new(1.0, 0.0, 1.0), Vector3D::new(2.0, 1.0, 2.0))));
+
+        let touching = Aabb::new(Vector3D::new(2.0, 0.0, 0.0), Vector3D::new(3.0, 2.0, 2.0));
+        assert_eq!(a.intersection(&touching),
+            Some(Aabb::new(Vector3D::new(2.0, 0.0, 0.0), Vector3D::new(2.0, 2.0, 2.0))));
+
+        let disjoint = Aabb::new(Vector3D::new(5.0, 5.0, 5.0), Vector3D::new(6.0, 6.0, 6.0));
+        assert_eq!(a.intersection(&disjoint), None);
+    }
+
+    #[test]
+    fn union() {
+        let a = Aabb::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(2.0, 2.0, 2.0));
+        let overlapping = Aabb::new(Vector3D::new(1.0, -1.0, 1.0), Vector3D::new(3.0, 1.0, 3.0));
+        assert_eq!(a.union(&overlapping), Aabb::new(Vector3D::new(0.0, -1.0, 0.0), Vector3D::new(3.0, 2.0, 3.0)));
+
+        let touching = Aabb::new(Vector3D::new(2.0, 0.0, 0.0), Vector3D::new(3.0, 2.0, 2.0));
+        assert_eq!(a.union(&touching), Aabb::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(3.0, 2.0, 2.0)));
+
+        let disjoint = Aabb::new(Vector3D::new(5.0, 5.0, 5.0), Vector3D::new(6.0, 6.0, 6.0));
+        assert_eq!(a.union(&disjoint), Aabb::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(6.0, 6.0, 6.0)));
+    }
+
+    #[test]
+    fn signed_distance() {
+        let aabb = Aabb::new(Vector3D::new(-1.0, -2.0, -3.0), Vector3D::new(1.0, 2.0, 3.0));
+        assert_approx_eq!(aabb.signed_distance(Vector3D::new(0.5, 0.0, 0.0)), -0.5);
+        assert_approx_eq!(aabb.signed_distance(Vector3D::new(0.0, 2.5, 1.0)), 0.5);
+        assert_approx_eq!(aabb.signed_distance(Vector3D::new(4.0, 6.0, 3.0)), 5.0);
+    }
+}
\ No newline at end of file
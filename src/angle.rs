@@ -0,0 +1,52 @@
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Angle {
+    radians: f64,
+}
+
+impl Angle {
+    pub fn degrees(degrees: f64) -> Self {
+        Self { radians: degrees.to_radians() }
+    }
+
+    pub fn radians(radians: f64) -> Self {
+        Self { radians }
+    }
+
+    pub fn to_degrees(&self) -> f64 {
+        self.radians.to_degrees()
+    }
+
+    pub fn to_radians(&self) -> f64 {
+        self.radians
+    }
+}
+
+/// Bare `f64` angles are read as degrees, matching the rotation builders' original signatures.
+/// Prefer `Angle::degrees` or `Angle::radians` in new code.
+impl From<f64> for Angle {
+    fn from(degrees: f64) -> Self {
+        Angle::degrees(degrees)
+    }
+}
+
+#[cfg(test)]
+mod angle_tests {
+    use super::*;
+    use crate::Matrix3D;
+
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn conversions() {
+        assert_approx_eq!(Angle::degrees(180.0).to_radians(), std::f64::consts::PI);
+        assert_approx_eq!(Angle::radians(std::f64::consts::FRAC_PI_2).to_degrees(), 90.0);
+        assert_eq!(Angle::from(45.0), Angle::degrees(45.0));
+    }
+
+    #[test]
+    fn rotation_builders() {
+        assert_eq!(Matrix3D::make_rotation_x(Angle::degrees(90.0)), Matrix3D::make_rotation_x(90.0));
+        assert_eq!(Matrix3D::make_rotation_y(Angle::degrees(30.0)), Matrix3D::make_rotation_y(30.0));
+        assert_eq!(Matrix3D::make_rotation_z(Angle::radians(0.5)), Matrix3D::make_rotation_z(0.5_f64.to_degrees()));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Color {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl Color {
+    pub fn from_rgba(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Self { r: r.clamp(0.0, 1.0), g: g.clamp(0.0, 1.0), b: b.clamp(0.0, 1.0), a: a.clamp(0.0, 1.0) }
+    }
+
+    pub fn lerp(&self, other: &Color, t: f64) -> Color {
+        Color::from_rgba(self.r + (other.r - self.r) * t,
+            self.g + (other.g - self.g) * t,
+            self.b + (other.b - self.b) * t,
+            self.a + (other.a - self.a) * t)
+    }
+
+    pub fn premultiply_alpha(&self) -> Color {
+        Color::from_rgba(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+    }
+
+    pub fn to_u8_array(&self) -> [u8; 4] {
+        let to_u8 = |c: f64| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        [to_u8(self.r), to_u8(self.g), to_u8(self.b), to_u8(self.a)]
+    }
+}
+
+#[cfg(test)]
+mod color_tests {
+    use super::*;
+
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn clamping() {
+        let c = Color::from_rgba(-0.5, 0.25, 1.5, 2.0);
+        assert_eq!(c, Color::from_rgba(0.0, 0.25, 1.0, 1.0));
+
+        let mut c = Color::from_rgba(0.0, 0.0, 0.0, 0.0);
+        c.r = 4.0;
+        c.g = -1.0;
+        assert_eq!(c.to_u8_array(), [255, 0, 0, 0]);
+    }
+
+    #[test]
+    fn byte_conversion() {
+        let c = Color::from_rgba(1.0, 0.5, 0.0, 0.2);
+        assert_eq!(c.to_u8_array(), [255, 128, 0, 51]);
+    }
+
+    #[test]
+    fn lerp() {
+        let black = Color::from_rgba(0.0, 0.0, 0.0, 1.0);
+        let white = Color::from_rgba(1.0, 1.0, 1.0, 1.0);
+        let gray = black.lerp(&white, 0.5);
+        assert_approx_eq!(gray.r, 0.5);
+        assert_approx_eq!(gray.g, 0.5);
+        assert_approx_eq!(gray.b, 0.5);
+        assert_approx_eq!(gray.a, 1.0);
+        assert_eq!(black.lerp(&white, 2.0), white);
+    }
+
+    #[test]
+    fn premultiplied_alpha() {
+        let c = Color::from_rgba(1.0, 0.5, 0.25, 0.5);
+        assert_eq!(c.premultiply_alpha(), Color::from_rgba(0.5, 0.25, 0.125, 0.5));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,153 @@
+use crate::Vector3D;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ellipsoid {
+    pub center: Vector3D,
+    pub radii: Vector3D,
+}
+
+impl Ellipsoid {
+    pub fn closest_point(&self, p: Vector3D) -> Vector3D {
+        let y = p - self.center;
+        let radii = [self.radii.x, self.radii.y, self.radii.z];
+        let x = closest_on_axes(radii, [y.x.abs(), y.y.abs(), y.z.abs()], [true; 3]);
+        let sign = |v: f64| if v < 0.0 { -1.0 } else { 1.0 };
+        self.center + Vector3D::new(x[0] * sign(y.x), x[1] * sign(y.y), x[2] * sign(y.z))
+    }
+
+    pub fn contains(&self, p: Vector3D) -> bool {
+        let y = p - self.center;
+        let s = Vector3D::new(y.x / self.radii.x, y.y / self.radii.y, y.z / self.radii.z);
+        s.dot(&s) <= 1.0
+    }
+
+    pub fn new(center: Vector3D, radii: Vector3D) -> Self {
+        Self { center, radii }
+    }
+}
+
+// Closest point on the ellipsoid restricted to the `active` axes, for a point with non-negative
+// coordinates. Newton's method on F(t) = sum((r_i y_i / (t + r_i²))²) - 1 finds the Lagrange
+// multiplier; when an interior point has no component along the smallest active radius, F has
+// no root past that pole, so the point either projects onto that axis or the problem drops it.
+fn closest_on_axes(r: [f64; 3], y: [f64; 3], active: [bool; 3]) -> [f64; 3] {
+    let axes: Vec<usize> = (0..3).filter(|&i| active[i]).collect();
+    let mut x = [0.0; 3];
+    if axes.len() == 1 {
+        x[axes[0]] = r[axes[0]];
+        return x;
+    }
+
+    let k = axes.iter().copied().fold(axes[0], |k, i| {
+        if r[i] < r[k] || (r[i] == r[k] && y[i] > y[k]) { i } else { k }
+    });
+    let inside = axes.iter().map(|&i| (y[i] / r[i]).powi(2)).sum::<f64>() <= 1.0;
+    if inside && y[k] <= f64::EPSILON * r[k] {
+        let mut d = 0.0;
+        for &i in axes.iter().filter(|&&i| i != k) {
+            if r[i] > r[k] {
+                x[i] = r[i] * r[i] * y[i] / (r[i] * r[i] - r[k] * r[k]);
+                d += (x[i] / r[i]).powi(2);
+            }
+        }
+        if d < 1.0 {
+            x[k] = r[k] * (1.0 - d).sqrt();
+            return x;
+        }
+        let mut reduced = active;
+        reduced[k] = false;
+        return closest_on_axes(r, y, reduced);
+    }
+
+    let pole = -r[k] * r[k];
+    let mut t = 0.0;
+    for _ in 0..64 {
+        let (mut f, mut df) = (-1.0, 0.0);
+        for &i in &axes {
+            let d = t + r[i] * r[i];
+            let q = r[i] * y[i] / d;
+            f += q * q;
+            df -= 2.0 * q * q / d;
+        }
+        if f.abs() <= 1e-12 {
+            break;
+        }
+        let mut next = t - f / df;
+        if next <= pole {
+            next = (t + pole) * 0.5;
+        }
+        t = next;
+    }
+    for &i in &axes {
+        x[i] = r[i] * r[i] * y[i] / (t + r[i] * r[i]);
+    }
+    x
+}
+
+#[cfg(test)]
+mod ellipsoid_tests {
+    use super::*;
+
+    use assert_approx_eq::assert_approx_eq;
+
+    fn on_surface(e: &Ellipsoid, p: Vector3D) -> f64 {
+        let y = p - e.center;
+        (y.x / e.radii.x).powi(2) + (y.y / e.radii.y).powi(2) + (y.z / e.radii.z).powi(2)
+    }
+
+    #[test]
+    fn sphere() {
+        let e = Ellipsoid::new(Vector3D::new(1.0, 2.0, 3.0), Vector3D::new(2.0, 2.0, 2.0));
+        let p = Vector3D::new(4.0, -1.0, 5.0);
+        let expected = e.center + (p - e.center).normalize() * 2.0;
+        for (r, e) in std::iter::zip(e.closest_point(p), expected) {
+            assert_approx_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn closest_point() {
+        let e = Ellipsoid::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(3.0, 2.0, 1.0));
+        for p in [Vector3D::new(4.0, 3.0, 2.0), Vector3D::new(1.0, 0.5, 0.25)] {
+            let q = e.closest_point(p);
+            assert_approx_eq!(on_surface(&e, q), 1.0);
+            let d = p - q;
+            let gradient = Vector3D::new(q.x / 9.0, q.y / 4.0, q.z);
+            assert_approx_eq!(d.cross(&gradient).magnitude(), 0.0);
+        }
+
+        let axis = e.closest_point(Vector3D::new(5.0, 0.0, 0.0));
+        assert_approx_eq!(axis.x, 3.0);
+    }
+
+    #[test]
+    fn degenerate_interior_points() {
+        let e = Ellipsoid::new(Vector3D::new(1.0, -1.0, 2.0), Vector3D::new(3.0, 2.0, 1.0));
+        let q = e.closest_point(e.center);
+        assert!(q.is_finite());
+        assert_approx_eq!((q - e.center).magnitude(), 1.0);
+        assert_approx_eq!(on_surface(&e, q), 1.0);
+
+        for offset in [Vector3D::new(0.5, 0.0, 0.0), Vector3D::new(0.0, -1.5, 0.0), Vector3D::new(2.9, 0.0, 0.0)] {
+            let p = e.center + offset;
+            let q = e.closest_point(p);
+            assert!(q.is_finite());
+            assert_approx_eq!(on_surface(&e, q), 1.0);
+            let local = q - e.center;
+            let gradient = Vector3D::new(local.x / 9.0, local.y / 4.0, local.z);
+            assert_approx_eq!((p - q).cross(&gradient).magnitude(), 0.0);
+        }
+
+        let q = e.closest_point(e.center + Vector3D::new(0.5, 0.0, 0.0)) - e.center;
+        assert_approx_eq!(q.x, 0.5625);
+        assert_approx_eq!(q.y, 0.0);
+        assert_approx_eq!(q.z, (1.0 - 0.0625_f64 * 0.5625).sqrt());
+    }
+
+    #[test]
+    fn contains() {
+        let e = Ellipsoid::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(3.0, 2.0, 1.0));
+        assert!(e.contains(Vector3D::new(1.0, 0.5, 0.25)));
+        assert!(!e.contains(Vector3D::new(0.0, 0.0, 1.5)));
+    }
+}
\ No newline at end of file
@@ -1,13 +1,29 @@
+pub use aabb::*;
+pub use angle::*;
+pub use color::*;
+pub use ellipsoid::*;
 pub use line::*;
 pub use matrix::*;
 pub use transform::*;
 pub use point::*;
 pub use plane::*;
+pub use quaternion::*;
+pub use ray::*;
+pub use sphere::*;
+pub use triangle::*;
 pub use vector::*;
 
+mod aabb;
+mod angle;
+mod color;
+mod ellipsoid;
 mod line;
 mod matrix;
 mod point;
 mod plane;
+mod quaternion;
+mod ray;
+mod sphere;
 mod transform;
+mod triangle;
 mod vector;
\ No newline at end of file
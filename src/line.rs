@@ -1,6 +1,6 @@
 use crate::{Vector3D, Transform4D, Matrix3D};
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Line {
     pub direction: Vector3D,
     pub moment: Vector3D,
@@ -12,6 +12,17 @@ impl Line {
             moment: Vector3D::new(mx, my, mz) }
     }
 
+    pub fn distance_to_line(&self, other: &Line) -> f64 {
+        let v = self.direction.cross(&other.direction);
+        let v2 = v.dot(&v);
+        if v2 > f64::EPSILON {
+            (self.direction.dot(&other.moment) + other.direction.dot(&self.moment)).abs() / v2.sqrt()
+        } else {
+            let p = self.direction.cross(&self.moment) / self.direction.dot(&self.direction);
+            (p.cross(&other.direction) - other.moment).magnitude() / other.direction.magnitude()
+        }
+    }
+
     pub fn from_vector(v: Vector3D, m: Vector3D) -> Self {
         Self { direction: v, moment: m }
     }
@@ -34,6 +45,20 @@ impl Line {
 mod line_tests {
     use super::*;
 
+    #[test]
+    fn line_distance() {
+        let l1 = Line::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let skew = Line::new(0.0, 1.0, 0.0, -2.0, 0.0, 0.0);
+        assert_eq!(l1.distance_to_line(&skew), 2.0);
+
+        let intersecting = Line::new(0.0, 1.0, 0.0, 0.0, 0.0, 0.0);
+        assert_eq!(l1.distance_to_line(&intersecting), 0.0);
+
+        let parallel = Line::new(1.0, 0.0, 0.0, 0.0, 0.0, -3.0);
+        assert_eq!(l1.distance_to_line(&parallel), 3.0);
+        assert_eq!(parallel.distance_to_line(&l1), 3.0);
+    }
+
     #[test]
     fn transform() {
         let l = Line::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
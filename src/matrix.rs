@@ -1,7 +1,32 @@
+use crate::aabb::Aabb;
+use crate::angle::Angle;
+use crate::line::Line;
+use crate::plane::Plane;
+use crate::point::{Point3D, Point4D};
+use crate::quaternion::Quaternion;
+use crate::sphere::Sphere;
 use crate::vector::{Vector3D, Vector4D};
 
 use std::fmt::Display;
-use std::ops::{Add, Index, Mul, Sub};
+use std::iter::{Product, Sum};
+use std::ops::{Add, Div, Index, Mul, Sub};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MatrixError {
+    Singular,
+    NonFinite,
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatrixError::Singular => write!(f, "matrix is singular"),
+            MatrixError::NonFinite => write!(f, "matrix contains non-finite elements"),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Matrix3D {
@@ -9,6 +34,18 @@ pub struct Matrix3D {
 }
 
 impl Matrix3D {
+    /// `extents` are the full side lengths of the box along x, y and z, not half-extents.
+    pub fn box_inertia(mass: f64, extents: Vector3D) -> Matrix3D {
+        let (w, h, d) = (extents.x * extents.x, extents.y * extents.y, extents.z * extents.z);
+        Matrix3D::make_scale(h + d, w + d, w + h) * (mass / 12.0)
+    }
+
+    /// Both frames hold their axes as columns and must be orthonormal, since the inverse of `to`
+    /// is taken as its transpose.
+    pub fn change_of_basis(from: &Matrix3D, to: &Matrix3D) -> Matrix3D {
+        to.transpose() * *from
+    }
+
     pub fn determinant(&self) -> f64 {
         self[0][0] * self[1][1] * self[2][2] +
         self[0][1] * self[1][2] * self[2][0] +
@@ -18,6 +55,7 @@ impl Matrix3D {
         self[0][2] * self[1][1] * self[2][0]
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn new(n00: f64, n01: f64, n02: f64,
         n10: f64, n11: f64, n12: f64,
         n20: f64, n21: f64, n22: f64) -> Self {
@@ -31,11 +69,37 @@ impl Matrix3D {
         Self { n: [a, b, c] }
     }
 
+    pub fn exp_rotation(omega: Vector3D) -> Matrix3D {
+        let angle = omega.magnitude();
+        if angle > f64::EPSILON {
+            Matrix3D::make_rotation(Angle::radians(angle), omega / angle)
+        } else {
+            Matrix3D::identity()
+        }
+    }
+
+    pub fn from_rotation_vector(omega: Vector3D) -> Matrix3D {
+        Matrix3D::exp_rotation(omega)
+    }
+
+    pub fn from_scale_rotation(scale: Vector3D, rotation_degrees_z: f64) -> Matrix3D {
+        Matrix3D::make_rotation_z(rotation_degrees_z) * Matrix3D::make_scale(scale.x, scale.y, scale.z)
+    }
+
     pub fn identity() -> Self {
         Matrix3D::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0)
     }
 
+    pub fn interpolate_rotation(&self, other: &Matrix3D, t: f64) -> Matrix3D {
+        let omega = (self.transpose() * *other).log_rotation();
+        *self * Matrix3D::exp_rotation(omega * t)
+    }
+
     pub fn inverse(&self) -> Option<Matrix3D> {
+        if *self == Matrix3D::identity() {
+            return Some(*self);
+        }
+
         let a = self[0];
         let b = self[1];
         let c = self[2];
@@ -54,6 +118,44 @@ impl Matrix3D {
         }
     }
 
+    pub fn is_finite(&self) -> bool {
+        self.n.iter().all(|v| v.is_finite())
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.n.iter().any(|v| v.is_nan())
+    }
+
+    pub fn log_rotation(&self) -> Vector3D {
+        let cos = ((self[0][0] + self[1][1] + self[2][2] - 1.0) * 0.5).clamp(-1.0, 1.0);
+        let v = Vector3D::new(self[2][1] - self[1][2], self[0][2] - self[2][0], self[1][0] - self[0][1]);
+        // sin(angle) from the skew part stays accurate near π, where sin(acos(cos)) does not.
+        let sin = v.magnitude() * 0.5;
+        let angle = sin.atan2(cos);
+
+        if cos >= 0.0 {
+            if sin > 1.0e-6 {
+                v * (angle / (2.0 * sin))
+            } else {
+                // Near zero, angle / sin(angle) ≈ 1 + angle² / 6.
+                v * (0.5 * (1.0 + sin * sin / 6.0))
+            }
+        } else {
+            let k = (0..3).fold(0, |k, i| if self[i][i] > self[k][k] { i } else { k });
+            let ak = ((self[k][k] - cos) / (1.0 - cos)).sqrt();
+            let mut a = [0.0; 3];
+            for (i, ai) in a.iter_mut().enumerate() {
+                *ai = if i == k { ak } else { (self[k][i] + self[i][k]) / (2.0 * (1.0 - cos) * ak) };
+            }
+            let axis = Vector3D::new(a[0], a[1], a[2]);
+            if axis.dot(&v) < 0.0 {
+                -axis * angle
+            } else {
+                axis * angle
+            }
+        }
+    }
+
     pub fn make_involution(a: Vector3D) -> Matrix3D {
         Matrix3D::new(
             2.0 * a.x.powi(2) - 1.0, 2.0 * a.x * a.y, 2.0 * a.x * a.z,
@@ -61,32 +163,54 @@ impl Matrix3D {
             2.0 * a.x * a.z, 2.0 * a.y * a.z, 2.0 * a.z.powi(2) - 1.0)
     }
 
-    pub fn make_rotation(t: f64, a: Vector3D) -> Matrix3D {
-        let r = t.to_radians();
+    pub fn make_look_rotation(forward: Vector3D, up: Vector3D) -> Matrix3D {
+        let z = forward.normalize();
+        let x = up.cross(&z).normalize();
+        let y = z.cross(&x);
+        Matrix3D::new(x.x, y.x, z.x, x.y, y.y, z.y, x.z, y.z, z.z)
+    }
+
+    pub fn make_look_rotation_safe(forward: Vector3D, up: Vector3D) -> Matrix3D {
+        let z = forward.normalize();
+        if up.cross(&z).magnitude() <= 1.0e-6 * up.magnitude() {
+            return Matrix3D::make_look_rotation(forward, z.any_perpendicular());
+        }
+        Matrix3D::make_look_rotation(forward, up)
+    }
+
+    pub fn make_look_with_roll(forward: Vector3D, roll_degrees: f64) -> Matrix3D {
+        let up = Vector3D::new(0.0, 1.0, 0.0);
+        Matrix3D::make_look_rotation(forward, up) * Matrix3D::make_rotation_z(roll_degrees)
+    }
+
+    pub fn make_rotation(t: impl Into<Angle>, a: Vector3D) -> Matrix3D {
+        let r = t.into().to_radians();
         let c = r.cos();
         let s = r.sin();
+        // 1 - cos(r) written as 2 sin²(r/2) to keep precision for small angles.
+        let d = 2.0 * (r * 0.5).sin().powi(2);
         Matrix3D::new(
-            c + (1.0 - c) * a.x.powi(2), (1.0 - c) * a.x * a.y - s * a.z, (1.0 - c) * a.x * a.z + s * a.y,
-            (1.0 - c) * a.x * a.y + s * a.z, c + (1.0 - c) * a.y.powi(2), (1.0 - c) * a.y * a.z - s * a.x,
-            (1.0 - c) * a.x * a.z - s * a.y, (1.0 - c) * a.y * a.z + s * a.x, c + (1.0 - c) * a.z.powi(2))
+            c + d * a.x.powi(2), d * a.x * a.y - s * a.z, d * a.x * a.z + s * a.y,
+            d * a.x * a.y + s * a.z, c + d * a.y.powi(2), d * a.y * a.z - s * a.x,
+            d * a.x * a.z - s * a.y, d * a.y * a.z + s * a.x, c + d * a.z.powi(2))
     }
 
-    pub fn make_rotation_x(t: f64) -> Matrix3D {
-        let r = t.to_radians();
+    pub fn make_rotation_x(t: impl Into<Angle>) -> Matrix3D {
+        let r = t.into().to_radians();
         let c = r.cos();
         let s = r.sin();
         Matrix3D::new(1.0, 0.0, 0.0, 0.0, c, -s, 0.0, s, c)
     }
 
-    pub fn make_rotation_y(t: f64) -> Matrix3D {
-        let r = t.to_radians();
+    pub fn make_rotation_y(t: impl Into<Angle>) -> Matrix3D {
+        let r = t.into().to_radians();
         let c = r.cos();
         let s = r.sin();
         Matrix3D::new(c, 0.0, s, 0.0, 1.0, 0.0, -s, 0.0, c)
     }
 
-    pub fn make_rotation_z(t: f64) -> Matrix3D {
-        let r = t.to_radians();
+    pub fn make_rotation_z(t: impl Into<Angle>) -> Matrix3D {
+        let r = t.into().to_radians();
         let c = r.cos();
         let s = r.sin();
         Matrix3D::new(c, -s, 0.0, s, c, 0.0, 0.0, 0.0, 1.0)
@@ -118,6 +242,148 @@ impl Matrix3D {
             a.z * b.x * t, a.z * b.y * t, a.z * b.z * t + 1.0
         )
     }
+
+    pub fn try_inverse(&self) -> Result<Matrix3D, MatrixError> {
+        if !self.is_finite() {
+            return Err(MatrixError::NonFinite);
+        }
+        // The determinant is compared against the row scale so rounding noise on a singular matrix is not inverted.
+        let scale = self[0].magnitude() * self[1].magnitude() * self[2].magnitude();
+        if self.determinant().abs() <= f64::EPSILON * scale {
+            return Err(MatrixError::Singular);
+        }
+        self.inverse().ok_or(MatrixError::Singular)
+    }
+
+    pub fn nearest_rotation(&self) -> Matrix3D {
+        let mut m = *self;
+        for _ in 0..100 {
+            let next = match m.transpose().inverse() {
+                Some(inv) => (m + inv) * 0.5,
+                None => return m,
+            };
+            let delta = (next - m).into_iter().fold(0.0, |d: f64, e| d.max(e.abs()));
+            m = next;
+            if delta < 1.0e-12 {
+                break;
+            }
+        }
+        m
+    }
+
+    pub fn orthonormalize_keeping_column(&self, fixed_col: usize) -> Matrix3D {
+        let column = |j: usize| Vector3D::new(self[0][j], self[1][j], self[2][j]);
+        let (i, j, k) = (fixed_col, (fixed_col + 1) % 3, (fixed_col + 2) % 3);
+        let mut columns = [Vector3D::new(0.0, 0.0, 0.0); 3];
+        columns[i] = column(i).normalize();
+        columns[j] = column(j).reject(&columns[i]).normalize();
+        columns[k] = column(k).reject(&columns[i]).reject(&columns[j]).normalize();
+        Matrix3D::from_vector(columns[0], columns[1], columns[2]).transpose()
+    }
+
+    /// `S` is always symmetric positive semi-definite, so when the determinant is negative `R` is
+    /// orthogonal with determinant -1 and carries the reflection. For singular input the missing
+    /// axes of `R` are completed so that it is a proper rotation.
+    pub fn polar_decompose(&self) -> (Matrix3D, Matrix3D) {
+        let (vectors, values) = (self.transpose() * *self).symmetric_eigen();
+        let column = |m: &Matrix3D, j: usize| Vector3D::new(m[0][j], m[1][j], m[2][j]);
+        let mut order = [0, 1, 2];
+        order.sort_by(|&a, &b| values[b].total_cmp(&values[a]));
+        let v = order.map(|j| column(&vectors, j));
+        let sigma = order.map(|j| values[j].max(0.0).sqrt());
+
+        let tolerance = sigma[0] * 1.0e-12;
+        let mut u = [Vector3D::new(1.0, 0.0, 0.0), Vector3D::new(0.0, 1.0, 0.0), Vector3D::new(0.0, 0.0, 1.0)];
+        if sigma[0] > tolerance {
+            u[0] = (*self * v[0]).normalize();
+        }
+        u[1] = if sigma[1] > tolerance { (*self * v[1]).normalize() } else { u[0].any_perpendicular().normalize() };
+        u[2] = if sigma[2] > tolerance { (*self * v[2]).normalize() } else { u[0].cross(&u[1]) };
+
+        let outer = |a: Vector3D, b: Vector3D| Matrix3D::from_vector(a * b.x, a * b.y, a * b.z).transpose();
+        let mut r = outer(u[0], v[0]) + outer(u[1], v[1]) + outer(u[2], v[2]);
+        if sigma[2] <= tolerance && r.determinant() < 0.0 {
+            r = r - outer(u[2], v[2]) * 2.0;
+        }
+        let s = r.transpose() * *self;
+        (r, (s + s.transpose()) * 0.5)
+    }
+
+    /// Singular values at or below `epsilon` are treated as zero.
+    pub fn pseudo_inverse(&self, epsilon: f64) -> Matrix3D {
+        let (vectors, values) = (self.transpose() * *self).symmetric_eigen();
+        let invert = |l: f64| if l.max(0.0).sqrt() > epsilon { 1.0 / l } else { 0.0 };
+        let inverse_values = Matrix3D::make_scale(invert(values.x), invert(values.y), invert(values.z));
+        vectors * inverse_values * vectors.transpose() * self.transpose()
+    }
+
+    pub fn rotation_angle(&self) -> f64 {
+        let trace = self[0][0] + self[1][1] + self[2][2];
+        ((trace - 1.0) * 0.5).clamp(-1.0, 1.0).acos()
+    }
+
+    pub fn shift_inertia(&self, mass: f64, offset: Vector3D) -> Matrix3D {
+        let r = offset;
+        let r2 = r.dot(&r);
+        *self + Matrix3D::new(
+            r2 - r.x * r.x, -r.x * r.y, -r.x * r.z,
+            -r.y * r.x, r2 - r.y * r.y, -r.y * r.z,
+            -r.z * r.x, -r.z * r.y, r2 - r.z * r.z) * mass
+    }
+
+    // Cyclic Jacobi sweeps; returns the eigenvectors as columns and the matching eigenvalues.
+    fn symmetric_eigen(&self) -> (Matrix3D, Vector3D) {
+        let mut a = *self;
+        let mut v = Matrix3D::identity();
+        for _ in 0..50 {
+            let off = a[0][1].powi(2) + a[0][2].powi(2) + a[1][2].powi(2);
+            if off <= f64::EPSILON * f64::EPSILON {
+                break;
+            }
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                if a[p][q].abs() <= f64::MIN_POSITIVE {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let mut j = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+                j[p][p] = c;
+                j[q][q] = c;
+                j[p][q] = t * c;
+                j[q][p] = -t * c;
+                let j = Matrix3D::new(j[0][0], j[0][1], j[0][2], j[1][0], j[1][1], j[1][2], j[2][0], j[2][1], j[2][2]);
+                a = j.transpose() * a * j;
+                v = v * j;
+            }
+        }
+        (v, Vector3D::new(a[0][0], a[1][1], a[2][2]))
+    }
+
+    /// `tbn * v` maps tangent space to world space; when the basis is orthonormal,
+    /// `tbn.transpose() * v` maps world space back to tangent space.
+    pub fn tbn(tangent: Vector3D, bitangent: Vector3D, normal: Vector3D) -> Matrix3D {
+        Matrix3D::new(tangent.x, bitangent.x, normal.x,
+            tangent.y, bitangent.y, normal.y,
+            tangent.z, bitangent.z, normal.z)
+    }
+
+    pub fn transpose(&self) -> Matrix3D {
+        Matrix3D::new(self[0][0], self[1][0], self[2][0],
+            self[0][1], self[1][1], self[2][1],
+            self[0][2], self[1][2], self[2][2])
+    }
+
+    /// Signed twist about `axis` in radians, in the range (-π, π].
+    pub fn twist_angle(&self, axis: Vector3D) -> f64 {
+        let q = Quaternion::from_matrix3d(self);
+        let (projection, w) = if q.w < 0.0 {
+            (-Vector3D::new(q.x, q.y, q.z).dot(&axis.normalize()), -q.w)
+        } else {
+            (Vector3D::new(q.x, q.y, q.z).dot(&axis.normalize()), q.w)
+        };
+        2.0 * projection.atan2(w)
+    }
 }
 
 impl Add<Self> for Matrix3D {
@@ -138,6 +404,15 @@ impl Display for Matrix3D {
     }
 }
 
+impl Div<f64> for Matrix3D {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self::Output {
+        Matrix3D::new(self[0][0] / rhs, self[0][1] / rhs, self[0][2] / rhs,
+        self[1][0] / rhs, self[1][1] / rhs, self[1][2] / rhs,
+        self[2][0] / rhs, self[2][1] / rhs, self[2][2] / rhs)
+    }
+}
+
 impl Index<usize> for Matrix3D {
     type Output = Vector3D;
     fn index(&self, index: usize) -> &Self::Output {
@@ -219,6 +494,12 @@ impl Mul<Vector3D> for Matrix3D {
 }
 
 
+impl Product for Matrix3D {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Matrix3D::identity(), |acc, m| acc * m)
+    }
+}
+
 impl Sub<Self> for Matrix3D {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
@@ -228,12 +509,51 @@ impl Sub<Self> for Matrix3D {
     }
 }
 
+impl Sum for Matrix3D {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Matrix3D::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), |acc, m| acc + m)
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Matrix4D {
     n: [Vector4D; 4],
 }
 
 impl Matrix4D {
+    pub fn blend(&self, other: &Matrix4D, t: f64) -> Matrix4D {
+        let (t0, r0, s0) = self.decompose();
+        let (t1, r1, s1) = other.decompose();
+        Matrix4D::recompose(t0 * (1.0 - t) + t1 * t, r0.slerp(&r1, t), s0 * (1.0 - t) + s1 * t)
+    }
+
+    pub fn cumulative_product(matrices: &[Matrix4D]) -> Vec<Matrix4D> {
+        let mut products: Vec<Matrix4D> = Vec::with_capacity(matrices.len());
+        for m in matrices {
+            let product = match products.last() {
+                Some(p) => *p * *m,
+                None => *m,
+            };
+            products.push(product);
+        }
+        products
+    }
+
+    /// Splits an affine transform without shear into translation, rotation and per-axis scale.
+    /// A negative determinant is carried by the x scale.
+    pub fn decompose(&self) -> (Vector3D, Quaternion, Vector3D) {
+        let c0 = Vector3D::new(self[0][0], self[1][0], self[2][0]);
+        let c1 = Vector3D::new(self[0][1], self[1][1], self[2][1]);
+        let c2 = Vector3D::new(self[0][2], self[1][2], self[2][2]);
+        let mut scale = Vector3D::new(c0.magnitude(), c1.magnitude(), c2.magnitude());
+        if self.linear_determinant() < 0.0 {
+            scale.x = -scale.x;
+        }
+        let rotation = Matrix3D::from_vector(c0 / scale.x, c1 / scale.y, c2 / scale.z).transpose();
+        let translation = Vector3D::new(self[0][3], self[1][3], self[2][3]);
+        (translation, Quaternion::from_matrix3d(&rotation), scale)
+    }
+
     pub fn determinant(&self) -> f64 {
         self[0][0] * self[1][1] * self[2][2] * self[3][3] +
         self[0][0] * self[1][2] * self[2][3] * self[3][1] +
@@ -261,6 +581,44 @@ impl Matrix4D {
         self[0][1] * self[1][3] * self[2][2] * self[3][0]
     }
 
+    pub fn determinant_sign(&self) -> i8 {
+        let mut lu = [[0.0; 4]; 4];
+        let mut scale: f64 = 0.0;
+        for (i, row) in lu.iter_mut().enumerate() {
+            for (j, e) in row.iter_mut().enumerate() {
+                *e = self[i][j];
+                scale = scale.max(e.abs());
+            }
+        }
+        if scale == 0.0 {
+            return 0;
+        }
+
+        let mut sign = 1;
+        for k in 0..4 {
+            let pivot = (k..4).max_by(|&a, &b| lu[a][k].abs().total_cmp(&lu[b][k].abs())).unwrap();
+            if lu[pivot][k].abs() <= f64::EPSILON * scale {
+                return 0;
+            }
+            if pivot != k {
+                lu.swap(pivot, k);
+                sign = -sign;
+            }
+            if lu[k][k] < 0.0 {
+                sign = -sign;
+            }
+            let pivot_row = lu[k];
+            for row in lu.iter_mut().skip(k + 1) {
+                let f = row[k] / pivot_row[k];
+                for j in k..4 {
+                    row[j] -= f * pivot_row[j];
+                }
+            }
+        }
+        sign
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn new(n00: f64, n01: f64, n02: f64, n03: f64,
         n10: f64, n11: f64, n12: f64, n13: f64,
         n20: f64, n21: f64, n22: f64, n23: f64,
@@ -272,16 +630,70 @@ impl Matrix4D {
             Self { n: [n1, n2, n3, n4] }
     }
 
+    /// Treats `a`, `b`, `c` and `d` as rows, same as `from_rows`.
     pub fn from_vector(a: Vector4D, b: Vector4D, c: Vector4D, d: Vector4D) -> Self {
         let n = [a, b, c, d];
         Self { n }
     }
 
+    pub fn from_columns(c0: Vector4D, c1: Vector4D, c2: Vector4D, c3: Vector4D) -> Self {
+        Matrix4D::new(c0.x, c1.x, c2.x, c3.x,
+            c0.y, c1.y, c2.y, c3.y,
+            c0.z, c1.z, c2.z, c3.z,
+            c0.w, c1.w, c2.w, c3.w)
+    }
+
+    pub fn from_rows(r0: Vector4D, r1: Vector4D, r2: Vector4D, r3: Vector4D) -> Self {
+        Self { n: [r0, r1, r2, r3] }
+    }
+
+    pub fn from_column_major(a: &[f64; 16]) -> Self {
+        Matrix4D::new(a[0], a[4], a[8], a[12],
+            a[1], a[5], a[9], a[13],
+            a[2], a[6], a[10], a[14],
+            a[3], a[7], a[11], a[15])
+    }
+
+    pub fn from_gltf_array(a: &[f64; 16]) -> Self {
+        Matrix4D::from_column_major(a)
+    }
+
+    pub fn from_row_major(a: &[f64; 16]) -> Self {
+        Matrix4D::new(a[0], a[1], a[2], a[3],
+            a[4], a[5], a[6], a[7],
+            a[8], a[9], a[10], a[11],
+            a[12], a[13], a[14], a[15])
+    }
+
+    pub fn from_basis(x_axis: Vector3D, y_axis: Vector3D, z_axis: Vector3D, origin: Vector3D) -> Self {
+        Matrix4D::new(x_axis.x, y_axis.x, z_axis.x, origin.x,
+            x_axis.y, y_axis.y, z_axis.y, origin.y,
+            x_axis.z, y_axis.z, z_axis.z, origin.z,
+            0.0, 0.0, 0.0, 1.0)
+    }
+
+    pub fn fit_box_to_box(src: &Aabb, dst: &Aabb) -> Matrix4D {
+        let scale = |s0: f64, s1: f64, d0: f64, d1: f64| {
+            if (s1 - s0).abs() > f64::EPSILON { (d1 - d0) / (s1 - s0) } else { 1.0 }
+        };
+        let sx = scale(src.min.x, src.max.x, dst.min.x, dst.max.x);
+        let sy = scale(src.min.y, src.max.y, dst.min.y, dst.max.y);
+        let sz = scale(src.min.z, src.max.z, dst.min.z, dst.max.z);
+        Matrix4D::new(sx, 0.0, 0.0, dst.min.x - sx * src.min.x,
+            0.0, sy, 0.0, dst.min.y - sy * src.min.y,
+            0.0, 0.0, sz, dst.min.z - sz * src.min.z,
+            0.0, 0.0, 0.0, 1.0)
+    }
+
     pub fn identity() -> Self {
         Matrix4D::new(1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0)
     }
 
     pub fn inverse(&self) -> Option<Matrix4D> {
+        if *self == Matrix4D::identity() {
+            return Some(*self);
+        }
+
         let a = Vector3D::new(self[0][0], self[1][0], self[2][0]);
         let b = Vector3D::new(self[0][1], self[1][1], self[2][1]);
         let c = Vector3D::new(self[0][2], self[1][2], self[2][2]);
@@ -319,6 +731,138 @@ impl Matrix4D {
         }
     }
 
+    pub fn is_finite(&self) -> bool {
+        self.n.iter().all(|v| v.is_finite())
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.n.iter().any(|v| v.is_nan())
+    }
+
+    pub fn linear_determinant(&self) -> f64 {
+        Matrix3D::new(self[0][0], self[0][1], self[0][2],
+            self[1][0], self[1][1], self[1][2],
+            self[2][0], self[2][1], self[2][2]).determinant()
+    }
+
+    pub fn make_billboard(object_pos: Vector3D, camera_pos: Vector3D, up: Vector3D, cylindrical: bool) -> Matrix4D {
+        let up = up.normalize();
+        let mut forward = camera_pos - object_pos;
+        if cylindrical {
+            forward = forward.reject(&up);
+        }
+        let z = forward.normalize();
+        let mut x = up.cross(&z);
+        if x.magnitude() <= 1.0e-6 {
+            x = z.any_perpendicular();
+        }
+        let x = x.normalize();
+        let y = if cylindrical { up } else { z.cross(&x) };
+        Matrix4D::from_basis(x, y, z, object_pos)
+    }
+
+    pub fn make_line_reflection(line: &Line) -> Matrix4D {
+        let v2 = line.direction.dot(&line.direction);
+        let p = line.direction.cross(&line.moment) / v2;
+        let m = Matrix3D::make_involution(line.direction / v2.sqrt());
+        let t = p - m * p;
+        Matrix4D::new(m[0][0], m[0][1], m[0][2], t.x,
+            m[1][0], m[1][1], m[1][2], t.y,
+            m[2][0], m[2][1], m[2][2], t.z,
+            0.0, 0.0, 0.0, 1.0)
+    }
+
+    pub fn make_point_reflection(center: Vector3D) -> Matrix4D {
+        Matrix4D::new(-1.0, 0.0, 0.0, 2.0 * center.x,
+            0.0, -1.0, 0.0, 2.0 * center.y,
+            0.0, 0.0, -1.0, 2.0 * center.z,
+            0.0, 0.0, 0.0, 1.0)
+    }
+
+    pub fn make_viewport(x: f64, y: f64, width: f64, height: f64, near: f64, far: f64) -> Matrix4D {
+        let hw = width * 0.5;
+        let hh = height * 0.5;
+        let hd = (far - near) * 0.5;
+        Matrix4D::new(hw, 0.0, 0.0, x + hw,
+            0.0, hh, 0.0, y + hh,
+            0.0, 0.0, hd, near + hd,
+            0.0, 0.0, 0.0, 1.0)
+    }
+
+    pub fn with_oblique_clip_plane(&self, clip_plane: Plane) -> Matrix4D {
+        let c = Vector4D::new(clip_plane.x, clip_plane.y, clip_plane.z, clip_plane.w);
+        match self.inverse() {
+            Some(inv) => {
+                let q = inv * Vector4D::new(c.x.signum(), c.y.signum(), 1.0, 1.0);
+                let c = c * (2.0 / c.dot(&q));
+                Matrix4D::from_vector(self[0], self[1], c - self[3], self[3])
+            }
+            None => *self,
+        }
+    }
+
+    pub fn mul_linear(&self, m3: &Matrix3D) -> Matrix4D {
+        let l = Matrix3D::new(self[0][0], self[0][1], self[0][2],
+            self[1][0], self[1][1], self[1][2],
+            self[2][0], self[2][1], self[2][2]) * *m3;
+        Matrix4D::new(l[0][0], l[0][1], l[0][2], self[0][3],
+            l[1][0], l[1][1], l[1][2], self[1][3],
+            l[2][0], l[2][1], l[2][2], self[2][3],
+            self[3][0], self[3][1], self[3][2], self[3][3])
+    }
+
+    pub fn orthonormalized_rigid(&self) -> Matrix4D {
+        let c0 = Vector3D::new(self[0][0], self[1][0], self[2][0]);
+        let c1 = Vector3D::new(self[0][1], self[1][1], self[2][1]);
+        let t = Vector3D::new(self[0][3], self[1][3], self[2][3]);
+
+        let x = c0.normalize();
+        let y = c1.reject(&x).normalize();
+        let z = x.cross(&y);
+        Matrix4D::from_basis(x, y, z, t)
+    }
+
+    pub fn product(matrices: &[Matrix4D]) -> Matrix4D {
+        matrices.iter().fold(Matrix4D::identity(), |p, m| p * *m)
+    }
+
+    /// Expects a view-projection matrix whose rows 1 and 3 are the projection's vertical scale
+    /// and depth rows applied to a rigid view transform.
+    pub fn projected_sphere_radius(&self, sphere: &Sphere, viewport_height: f64) -> f64 {
+        let c = sphere.center;
+        let depth = self[3][0] * c.x + self[3][1] * c.y + self[3][2] * c.z + self[3][3];
+        let vertical_scale = Vector3D::new(self[1][0], self[1][1], self[1][2]).magnitude();
+        sphere.radius * vertical_scale / depth * viewport_height * 0.5
+    }
+
+    pub fn recompose(translation: Vector3D, rotation: Quaternion, scale: Vector3D) -> Matrix4D {
+        let r = rotation.normalize().to_matrix3d();
+        Matrix4D::new(r[0][0] * scale.x, r[0][1] * scale.y, r[0][2] * scale.z, translation.x,
+            r[1][0] * scale.x, r[1][1] * scale.y, r[1][2] * scale.z, translation.y,
+            r[2][0] * scale.x, r[2][1] * scale.y, r[2][2] * scale.z, translation.z,
+            0.0, 0.0, 0.0, 1.0)
+    }
+
+    pub fn to_gltf_array(&self) -> [f64; 16] {
+        [self[0][0], self[1][0], self[2][0], self[3][0],
+            self[0][1], self[1][1], self[2][1], self[3][1],
+            self[0][2], self[1][2], self[2][2], self[3][2],
+            self[0][3], self[1][3], self[2][3], self[3][3]]
+    }
+
+    pub fn to_rotation_translation(&self) -> (Quaternion, Vector3D) {
+        let rotation = Matrix3D::new(self[0][0], self[0][1], self[0][2],
+            self[1][0], self[1][1], self[1][2],
+            self[2][0], self[2][1], self[2][2]);
+        (Quaternion::from_matrix3d(&rotation), Vector3D::new(self[0][3], self[1][3], self[2][3]))
+    }
+
+    pub fn transpose(&self) -> Matrix4D {
+        Matrix4D::new(self[0][0], self[1][0], self[2][0], self[3][0],
+            self[0][1], self[1][1], self[2][1], self[3][1],
+            self[0][2], self[1][2], self[2][2], self[3][2],
+            self[0][3], self[1][3], self[2][3], self[3][3])
+    }
 }
 
 impl Add<Self> for Matrix4D {
@@ -341,6 +885,16 @@ impl Display for Matrix4D {
     }
 }
 
+impl Div<f64> for Matrix4D {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self::Output {
+        Matrix4D::new(self[0][0] / rhs, self[0][1] / rhs, self[0][2] / rhs, self[0][3] / rhs,
+        self[1][0] / rhs, self[1][1] / rhs, self[1][2] / rhs, self[1][3] / rhs,
+        self[2][0] / rhs, self[2][1] / rhs, self[2][2] / rhs, self[2][3] / rhs,
+        self[3][0] / rhs, self[3][1] / rhs, self[3][2] / rhs, self[3][3] / rhs)
+    }
+}
+
 impl Index<usize> for Matrix4D {
     type Output = Vector4D;
     fn index(&self, index: usize) -> &Self::Output {
@@ -392,6 +946,14 @@ impl Mul<Self> for Matrix4D {
     }
 }
 
+impl Mul<Point4D> for Matrix4D {
+    type Output = Point3D;
+    fn mul(self, rhs: Point4D) -> Self::Output {
+        let v = self * Vector4D::new(rhs.x, rhs.y, rhs.z, rhs.w);
+        Point4D::new(v.x, v.y, v.z, v.w).to_point3d()
+    }
+}
+
 impl Mul<Vector4D> for Matrix4D {
     type Output = Vector4D;
     fn mul(self, rhs: Vector4D) -> Self::Output {
@@ -403,6 +965,12 @@ impl Mul<Vector4D> for Matrix4D {
     }
 }
 
+impl Product for Matrix4D {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Matrix4D::identity(), |acc, m| acc * m)
+    }
+}
+
 impl Sub<Self> for Matrix4D {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
@@ -414,13 +982,20 @@ impl Sub<Self> for Matrix4D {
     }
 }
 
+impl Sum for Matrix4D {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Matrix4D::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), |acc, m| acc + m)
+    }
+}
+
 #[cfg(test)]
 mod matrix3d_tests {
     use super::*;
+    use crate::Quaternion;
     use assert_approx_eq::assert_approx_eq;
     use num_traits::Float;
 
-    fn element_approx_eq<I: IntoIterator>(result: I, expected: I) -> ()
+    fn element_approx_eq<I: IntoIterator>(result: I, expected: I)
         where I::Item: Float,
               I::Item: std::fmt::Debug {
         for (r, e) in std::iter::zip(result, expected) {
@@ -576,6 +1151,33 @@ mod matrix3d_tests {
         assert_approx_eq!(matrix_product[2][2], identity_matrix[2][2]);
     }
 
+    #[test]
+    fn iterator_sum_product() {
+        let matrices = [Matrix3D::make_rotation_x(30.0), Matrix3D::make_scale(1.0, 2.0, 3.0), Matrix3D::make_rotation_z(45.0)];
+        assert_eq!(matrices.iter().copied().sum::<Matrix3D>(), matrices[0] + matrices[1] + matrices[2]);
+        assert_eq!(matrices.iter().copied().product::<Matrix3D>(), matrices[0] * matrices[1] * matrices[2]);
+        assert_eq!(std::iter::empty::<Matrix3D>().product::<Matrix3D>(), Matrix3D::identity());
+        assert_eq!(std::iter::empty::<Matrix3D>().sum::<Matrix3D>(), Matrix3D::identity() * 0.0);
+    }
+
+    #[test]
+    fn scalar_division() {
+        let m = Matrix3D::new(1.0, -2.0, 3.0, 4.5, 5.0, -6.0, 7.0, 8.25, 9.0);
+        assert_eq!(m / 2.0, m * 0.5);
+    }
+
+    #[test]
+    fn identity_inversion() {
+        assert_eq!(Matrix3D::identity().inverse(), Some(Matrix3D::identity()));
+
+        let near_identity = Matrix3D::new(1.0 + 1e-9, 0.0, 0.0, 0.0, 1.0, 1e-9, 0.0, 0.0, 1.0);
+        let inverted = near_identity.inverse().unwrap();
+        assert_ne!(inverted, Matrix3D::identity());
+        for (r, e) in std::iter::zip(inverted * near_identity, Matrix3D::identity()) {
+            assert_approx_eq!(r, e, 1e-15);
+        }
+    }
+
     #[test]
     fn rotation() {
         let matrix = Matrix3D::identity();
@@ -593,6 +1195,57 @@ mod matrix3d_tests {
         element_approx_eq(a_rot * matrix[2], Vector3D::new(0.5_f64.sqrt(), -0.5_f64.sqrt(), 0.0));
     }
 
+    #[test]
+    fn small_angle_rotation() {
+        let a = Vector3D::new(0.5_f64.sqrt(), 0.5_f64.sqrt(), 0.0);
+        let t: f64 = 1.0e-7;
+        let r = t.to_radians();
+        let m = Matrix3D::make_rotation(t, a);
+        let expected = (r * r / 2.0 - r.powi(4) / 24.0) * a.x * a.y;
+        assert!(((m[0][1] - expected) / expected).abs() < 1.0e-9);
+
+        let naive = (1.0 - r.cos()) * a.x * a.y;
+        assert!(((naive - expected) / expected).abs() > 1.0e-3);
+    }
+
+    #[test]
+    fn look_rotation_safe() {
+        let forward = Vector3D::new(1.0, 2.0, -0.5);
+        let up = Vector3D::new(0.0, 1.0, 0.0);
+        assert_eq!(Matrix3D::make_look_rotation_safe(forward, up), Matrix3D::make_look_rotation(forward, up));
+
+        for up in [forward, -forward * 3.0] {
+            let m = Matrix3D::make_look_rotation_safe(forward, up);
+            assert!(m.is_finite());
+            for (r, e) in std::iter::zip(m * Vector3D::new(0.0, 0.0, 1.0), forward.normalize()) {
+                assert_approx_eq!(r, e);
+            }
+            for (r, e) in std::iter::zip(m.transpose() * m, Matrix3D::identity()) {
+                assert_approx_eq!(r, e);
+            }
+        }
+    }
+
+    #[test]
+    fn look_with_roll() {
+        let forward = Vector3D::new(1.0, 0.5, -2.0);
+        let up = Vector3D::new(0.0, 1.0, 0.0);
+        let look = Matrix3D::make_look_rotation(forward, up);
+        for (r, e) in std::iter::zip(look * Vector3D::new(0.0, 0.0, 1.0), forward.normalize()) {
+            assert_approx_eq!(r, e);
+        }
+        assert_eq!(Matrix3D::make_look_with_roll(forward, 0.0), look);
+
+        let rolled = Matrix3D::make_look_with_roll(forward, 90.0);
+        let right = rolled * Vector3D::new(1.0, 0.0, 0.0);
+        for (r, e) in std::iter::zip(right, look * Vector3D::new(0.0, 1.0, 0.0)) {
+            assert_approx_eq!(r, e);
+        }
+        for (r, e) in std::iter::zip(rolled * Vector3D::new(0.0, 0.0, 1.0), forward.normalize()) {
+            assert_approx_eq!(r, e);
+        }
+    }
+
     #[test]
     fn reflection() {
         use std::f64::consts::PI;
@@ -632,6 +1285,336 @@ mod matrix3d_tests {
         let m = Matrix3D::new(1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 3.0, 3.0);
         element_approx_eq(skew * m, Matrix3D::new(3.0, 3.0, 3.0, 2.0, 2.0, 2.0, 3.0, 3.0, 3.0));
     }
+
+    #[test]
+    fn finite_and_nan() {
+        let m = Matrix3D::identity();
+        assert!(m.is_finite());
+        assert!(!m.is_nan());
+
+        let m = Matrix3D::new(1.0, 0.0, 0.0, 0.0, f64::INFINITY, 0.0, 0.0, 0.0, 1.0);
+        assert!(!m.is_finite());
+        assert!(!m.is_nan());
+
+        let m = Matrix3D::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, f64::NAN);
+        assert!(!m.is_finite());
+        assert!(m.is_nan());
+    }
+
+    #[test]
+    fn rotation_log_exp() {
+        let axes = [Vector3D::new(1.0, 0.0, 0.0),
+            Vector3D::new(1.0, 2.0, 3.0).normalize(),
+            Vector3D::new(-0.5, 0.25, 1.0).normalize()];
+        for a in axes {
+            for t in [10.0, 90.0, 135.0, 180.0] {
+                let m = Matrix3D::make_rotation(t, a);
+                for (r, e) in std::iter::zip(Matrix3D::exp_rotation(m.log_rotation()), m) {
+                    assert_approx_eq!(r, e);
+                }
+            }
+        }
+
+        let omega = Vector3D::new(0.3, -0.2, 0.1);
+        let log = Matrix3D::exp_rotation(omega).log_rotation();
+        assert_approx_eq!(log.x, omega.x);
+        assert_approx_eq!(log.y, omega.y);
+        assert_approx_eq!(log.z, omega.z);
+
+        let small = Matrix3D::exp_rotation(Vector3D::new(1.0e-9, -2.0e-9, 0.5e-9));
+        for (r, e) in std::iter::zip(small, Matrix3D::identity()) {
+            assert_approx_eq!(r, e);
+        }
+        assert_eq!(Matrix3D::exp_rotation(Vector3D::new(0.0, 0.0, 0.0)), Matrix3D::identity());
+
+        for omega in [Vector3D::new(0.0, 0.0, 1.0e-7), Vector3D::new(6.0e-8, -8.0e-8, 0.0)] {
+            let log = Matrix3D::exp_rotation(omega).log_rotation();
+            for (r, e) in std::iter::zip(log, omega) {
+                assert_approx_eq!(r, e, 1.0e-15);
+            }
+        }
+
+        let omega = Vector3D::new(2.0, -1.0, 2.0).normalize() * 179.9999_f64.to_radians();
+        let m = Matrix3D::exp_rotation(omega);
+        for (r, e) in std::iter::zip(m.log_rotation(), omega) {
+            assert_approx_eq!(r, e, 1.0e-9);
+        }
+        for (r, e) in std::iter::zip(Matrix3D::exp_rotation(m.log_rotation()), m) {
+            assert_approx_eq!(r, e, 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn nearest_rotation() {
+        let r = Matrix3D::make_rotation(40.0, Vector3D::new(1.0, -1.0, 2.0).normalize());
+        for (n, e) in std::iter::zip(r.nearest_rotation(), r) {
+            assert_approx_eq!(n, e);
+        }
+        for (n, e) in std::iter::zip((r * 3.0).nearest_rotation(), r) {
+            assert_approx_eq!(n, e);
+        }
+        for (n, e) in std::iter::zip((r * Matrix3D::make_scale(1.0, 2.0, 3.0)).nearest_rotation(), r) {
+            assert_approx_eq!(n, e);
+        }
+    }
+
+    #[test]
+    fn rotation_vector() {
+        let pairs = [(30.0, Vector3D::new(1.0, 0.0, 0.0)),
+            (-75.0, Vector3D::new(0.0, 1.0, 1.0).normalize()),
+            (160.0, Vector3D::new(3.0, -2.0, 1.0).normalize())];
+        for (angle, axis) in pairs {
+            let omega = axis * f64::to_radians(angle);
+            for (r, e) in std::iter::zip(Matrix3D::from_rotation_vector(omega), Matrix3D::make_rotation(angle, axis)) {
+                assert_approx_eq!(r, e);
+            }
+        }
+        assert_eq!(Matrix3D::from_rotation_vector(Vector3D::new(0.0, 0.0, 0.0)), Matrix3D::identity());
+    }
+
+    #[test]
+    fn rotation_interpolation() {
+        let axis_a = Vector3D::new(1.0, 0.0, 1.0).normalize();
+        let axis_b = Vector3D::new(0.0, 1.0, 2.0).normalize();
+        let a = Matrix3D::make_rotation(30.0, axis_a);
+        let b = Matrix3D::make_rotation(110.0, axis_b);
+        for (r, e) in std::iter::zip(a.interpolate_rotation(&b, 0.0), a) {
+            assert_approx_eq!(r, e);
+        }
+        for (r, e) in std::iter::zip(a.interpolate_rotation(&b, 1.0), b) {
+            assert_approx_eq!(r, e);
+        }
+
+        let mid = a.interpolate_rotation(&b, 0.5);
+        assert_approx_eq!(mid.determinant(), 1.0);
+        for (r, e) in std::iter::zip(mid * mid.transpose(), Matrix3D::identity()) {
+            assert_approx_eq!(r, e);
+        }
+
+        let qa = axis_a * 15.0_f64.to_radians().sin();
+        let qa = Quaternion::new(qa.x, qa.y, qa.z, 15.0_f64.to_radians().cos());
+        let qb = axis_b * 55.0_f64.to_radians().sin();
+        let qb = Quaternion::new(qb.x, qb.y, qb.z, 55.0_f64.to_radians().cos());
+        for (r, e) in std::iter::zip(mid, qa.slerp(&qb, 0.5).to_matrix3d()) {
+            assert_approx_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn change_of_basis() {
+        let frame = Matrix3D::make_rotation(60.0, Vector3D::new(0.0, 1.0, 1.0).normalize());
+        let local = Vector3D::new(1.0, -2.0, 0.5);
+        let world = Matrix3D::change_of_basis(&frame, &Matrix3D::identity()) * local;
+        let expected = Vector3D::new(frame[0][0], frame[1][0], frame[2][0]) * local.x
+            + Vector3D::new(frame[0][1], frame[1][1], frame[2][1]) * local.y
+            + Vector3D::new(frame[0][2], frame[1][2], frame[2][2]) * local.z;
+        for (r, e) in std::iter::zip(world, expected) {
+            assert_approx_eq!(r, e);
+        }
+
+        let back = Matrix3D::change_of_basis(&Matrix3D::identity(), &frame) * world;
+        for (r, e) in std::iter::zip(back, local) {
+            assert_approx_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn box_inertia() {
+        let (m, w, h, d) = (3.0, 2.0, 4.0, 5.0);
+        let inertia = Matrix3D::box_inertia(m, Vector3D::new(w, h, d));
+        assert_approx_eq!(inertia[0][0], m * (h * h + d * d) / 12.0);
+        assert_approx_eq!(inertia[1][1], m * (w * w + d * d) / 12.0);
+        assert_approx_eq!(inertia[2][2], m * (w * w + h * h) / 12.0);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(inertia[i][j], inertia[j][i]);
+                if i != j {
+                    assert_eq!(inertia[i][j], 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn orthonormalize_keeping_column() {
+        let m = Matrix3D::new(2.0, 0.3, -0.2, 0.1, 1.5, 0.4, -0.3, 0.2, 3.0);
+        for fixed in 0..3 {
+            let o = m.orthonormalize_keeping_column(fixed);
+            let original = Vector3D::new(m[0][fixed], m[1][fixed], m[2][fixed]).normalize();
+            for (r, e) in std::iter::zip(Vector3D::new(o[0][fixed], o[1][fixed], o[2][fixed]), original) {
+                assert_approx_eq!(r, e);
+            }
+            for (r, e) in std::iter::zip(o.transpose() * o, Matrix3D::identity()) {
+                assert_approx_eq!(r, e);
+            }
+        }
+    }
+
+    #[test]
+    fn scale_rotation() {
+        let m = Matrix3D::from_scale_rotation(Vector3D::new(2.0, 3.0, 1.0), 90.0);
+        for (r, e) in std::iter::zip(m * Vector3D::new(1.0, 0.0, 0.0), Vector3D::new(0.0, 2.0, 0.0)) {
+            assert_approx_eq!(r, e);
+        }
+        for (r, e) in std::iter::zip(m * Vector3D::new(0.0, 1.0, 0.0), Vector3D::new(-3.0, 0.0, 0.0)) {
+            assert_approx_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn polar_decompose() {
+        let rotation = Matrix3D::make_rotation(75.0, Vector3D::new(1.0, -2.0, 0.5).normalize());
+        let (r, s) = rotation.polar_decompose();
+        for (r, e) in std::iter::zip(r, rotation) {
+            assert_approx_eq!(r, e);
+        }
+        for (r, e) in std::iter::zip(s, Matrix3D::identity()) {
+            assert_approx_eq!(r, e);
+        }
+
+        let stretch = Matrix3D::new(2.0, 0.5, 0.0, 0.5, 3.0, 0.25, 0.0, 0.25, 1.5);
+        let (r, s) = stretch.polar_decompose();
+        for (r, e) in std::iter::zip(r, Matrix3D::identity()) {
+            assert_approx_eq!(r, e);
+        }
+        for (r, e) in std::iter::zip(s, stretch) {
+            assert_approx_eq!(r, e);
+        }
+
+        let reflecting = Matrix3D::make_scale(-1.0, 2.0, 3.0);
+        let (r, s) = reflecting.polar_decompose();
+        assert_approx_eq!(r.determinant(), -1.0);
+        for (r, e) in std::iter::zip(r, Matrix3D::make_scale(-1.0, 1.0, 1.0)) {
+            assert_approx_eq!(r, e);
+        }
+        for (r, e) in std::iter::zip(s, Matrix3D::make_scale(1.0, 2.0, 3.0)) {
+            assert_approx_eq!(r, e);
+        }
+        for (r, e) in std::iter::zip(r * s, reflecting) {
+            assert_approx_eq!(r, e);
+        }
+
+        let (r, s) = (rotation * stretch * reflecting).polar_decompose();
+        assert_approx_eq!(r.determinant(), -1.0);
+        for value in s.symmetric_eigen().1 {
+            assert!(value >= 0.0);
+        }
+        for (r, e) in std::iter::zip(r * s, rotation * stretch * reflecting) {
+            assert_approx_eq!(r, e);
+        }
+
+        let singular = rotation * Matrix3D::make_scale(2.0, 1.0, 0.0);
+        let (r, s) = singular.polar_decompose();
+        assert!(r.is_finite());
+        assert_approx_eq!(r.determinant(), 1.0);
+        for value in s.symmetric_eigen().1 {
+            assert!(value >= -1.0e-12);
+        }
+        for (r, e) in std::iter::zip(r * s, singular) {
+            assert_approx_eq!(r, e);
+        }
+
+        let m = rotation * stretch;
+        let (r, s) = m.polar_decompose();
+        assert_eq!(s, s.transpose());
+        assert_approx_eq!(r.determinant(), 1.0);
+        for (r, e) in std::iter::zip(r * s, m) {
+            assert_approx_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn pseudo_inverse() {
+        let m = Matrix3D::new(2.0, 1.0, 0.5, -1.0, 3.0, 0.0, 0.25, 1.0, 4.0);
+        for (r, e) in std::iter::zip(m.pseudo_inverse(1e-9), m.inverse().unwrap()) {
+            assert_approx_eq!(r, e);
+        }
+
+        let rank_two = Matrix3D::new(1.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 2.0);
+        let pinv = rank_two.pseudo_inverse(1e-9);
+        for (r, e) in std::iter::zip(pinv * Vector3D::new(2.0, 2.0, 4.0), Vector3D::new(1.0, 1.0, 2.0)) {
+            assert_approx_eq!(r, e);
+        }
+        for (r, e) in std::iter::zip(rank_two * pinv * rank_two, rank_two) {
+            assert_approx_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn rotation_angle() {
+        assert_eq!(Matrix3D::identity().rotation_angle(), 0.0);
+        let axis = Vector3D::new(1.0, 2.0, -2.0).normalize();
+        assert_approx_eq!(Matrix3D::make_rotation(90.0, axis).rotation_angle(), std::f64::consts::FRAC_PI_2);
+        assert_approx_eq!(Matrix3D::make_rotation(180.0, axis).rotation_angle(), std::f64::consts::PI);
+        assert_approx_eq!(Matrix3D::make_rotation_x(180.0).rotation_angle(), std::f64::consts::PI);
+    }
+
+    #[test]
+    fn shift_inertia() {
+        let m = 2.0;
+        let inertia = Matrix3D::box_inertia(m, Vector3D::new(1.0, 2.0, 3.0));
+        let shifted = inertia.shift_inertia(m, Vector3D::new(1.0, 2.0, 0.0));
+        let expected = Matrix3D::new(
+            4.0 * m, -2.0 * m, 0.0,
+            -2.0 * m, 1.0 * m, 0.0,
+            0.0, 0.0, 5.0 * m);
+        element_approx_eq(shifted - inertia, expected);
+    }
+
+    #[test]
+    fn transpose() {
+        let m = Matrix3D::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(m.transpose(), Matrix3D::new(1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0));
+        assert_eq!(m.transpose().transpose(), m);
+        assert_eq!(Matrix3D::identity().transpose(), Matrix3D::identity());
+    }
+
+    #[test]
+    fn twist_angle() {
+        let axis = Vector3D::new(1.0, 2.0, 2.0).normalize();
+        assert_approx_eq!(Matrix3D::make_rotation(70.0, axis).twist_angle(axis), 70.0_f64.to_radians());
+        assert_approx_eq!(Matrix3D::make_rotation(-150.0, axis).twist_angle(axis * 3.0), -150.0_f64.to_radians());
+
+        let swing_axis = axis.any_perpendicular().normalize();
+        let m = Matrix3D::make_rotation(35.0, swing_axis) * Matrix3D::make_rotation(40.0, axis);
+        assert_approx_eq!(m.twist_angle(axis), 40.0_f64.to_radians());
+    }
+
+    #[test]
+    fn tangent_space() {
+        let r = Matrix3D::make_rotation(50.0, Vector3D::new(1.0, 1.0, 0.0).normalize());
+        let t = r * Vector3D::new(1.0, 0.0, 0.0);
+        let b = r * Vector3D::new(0.0, 1.0, 0.0);
+        let n = r * Vector3D::new(0.0, 0.0, 1.0);
+        let tbn = Matrix3D::tbn(t, b, n);
+        assert_eq!(tbn * Vector3D::new(0.0, 0.0, 1.0), n);
+        for (r, e) in std::iter::zip(tbn.transpose() * n, Vector3D::new(0.0, 0.0, 1.0)) {
+            assert_approx_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn try_inverse() {
+        let matrix = Matrix3D::new(1.0, 2.0, 3.0, 5.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(matrix.try_inverse(), Ok(matrix.inverse().unwrap()));
+
+        let singular = Matrix3D::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(singular.try_inverse(), Err(MatrixError::Singular));
+
+        let rounded = Matrix3D::new(0.1, 0.2, 0.3, 0.3, 0.6, 0.9, 1.0, 1.0, 2.0);
+        assert_eq!(rounded.try_inverse(), Err(MatrixError::Singular));
+
+        let small = Matrix3D::make_scale(1.0e-6, 1.0e-6, 1.0e-6);
+        assert_eq!(small.try_inverse(), Ok(small.inverse().unwrap()));
+
+        let non_finite = Matrix3D::new(f64::NAN, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+        assert_eq!(non_finite.try_inverse(), Err(MatrixError::NonFinite));
+
+        let infinite = Matrix3D::new(1.0, 0.0, 0.0, 0.0, f64::INFINITY, 0.0, 0.0, 0.0, 1.0);
+        assert_eq!(infinite.try_inverse(), Err(MatrixError::NonFinite));
+        assert_eq!(MatrixError::NonFinite.to_string(), "matrix contains non-finite elements");
+        assert_eq!(MatrixError::Singular.to_string(), "matrix is singular");
+    }
 }
 
 #[cfg(test)]
@@ -639,6 +1622,22 @@ mod matrix4d_tests {
     use super::*;
     use assert_approx_eq::assert_approx_eq;
 
+    #[test]
+    fn rotation_translation() {
+        let r = Matrix3D::make_rotation_z(90.0);
+        let t = Vector3D::new(1.0, -2.0, 3.0);
+        let m = Matrix4D::from_basis(r * Vector3D::new(1.0, 0.0, 0.0),
+            r * Vector3D::new(0.0, 1.0, 0.0), r * Vector3D::new(0.0, 0.0, 1.0), t);
+        let (q, translation) = m.to_rotation_translation();
+        let half = 45.0_f64.to_radians();
+        let expected = Quaternion::new(0.0, 0.0, half.sin(), half.cos());
+        assert_approx_eq!(q.x, expected.x);
+        assert_approx_eq!(q.y, expected.y);
+        assert_approx_eq!(q.z, expected.z);
+        assert_approx_eq!(q.w, expected.w);
+        assert_eq!(translation, t);
+    }
+
     #[test]
     fn constructor() {
         let matrix = Matrix4D::new(0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1, 1.2, 1.3, 1.4, 1.5, 1.6);
@@ -685,6 +1684,28 @@ mod matrix4d_tests {
         assert_eq!(matrix[3][3], 1.6);
     }
 
+    #[test]
+    fn row_and_column_constructors() {
+        let e0 = Vector4D::new(1.0, 0.0, 0.0, 0.0);
+        let e1 = Vector4D::new(0.0, 1.0, 0.0, 0.0);
+        let e2 = Vector4D::new(0.0, 0.0, 1.0, 0.0);
+        let e3 = Vector4D::new(0.0, 0.0, 0.0, 1.0);
+        assert_eq!(Matrix4D::from_columns(e0, e1, e2, e3), Matrix4D::identity());
+        assert_eq!(Matrix4D::from_rows(e0, e1, e2, e3), Matrix4D::identity());
+
+        let a = Vector4D::new(0.1, 0.2, 0.3, 0.4);
+        let b = Vector4D::new(0.5, 0.6, 0.7, 0.8);
+        let c = Vector4D::new(0.9, 1.0, 1.1, 1.2);
+        let d = Vector4D::new(1.3, 1.4, 1.5, 1.6);
+        let rows = Matrix4D::from_rows(a, b, c, d);
+        assert_eq!(rows, Matrix4D::from_vector(a, b, c, d));
+        assert_eq!(rows[1], b);
+
+        let columns = Matrix4D::from_columns(a, b, c, d);
+        assert_eq!(columns * e1, b);
+        assert_eq!(columns[0], Vector4D::new(0.1, 0.5, 0.9, 1.3));
+    }
+
     #[test]
     fn index() {
         let matrix = Matrix4D::new(0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1, 1.2, 1.3, 1.4, 1.5, 1.6);
@@ -775,6 +1796,7 @@ mod matrix4d_tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn matrix_multiplication() {
         let matrix1 = Matrix4D::new(0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1, 1.2, 1.3, 1.4, 1.5, 1.6);
         let matrix2 = Matrix4D::new(0.2, 0.4, 0.6, 0.8, 1.0, 1.2, 1.4, 1.6, 1.8, 2.0, 2.2, 2.4, 2.6, 2.8, 3.0, 3.2);
@@ -853,4 +1875,343 @@ mod matrix4d_tests {
         assert_approx_eq!(matrix_product[3][2], identity_matrix[3][2]);
         assert_approx_eq!(matrix_product[3][3], identity_matrix[3][3]);
     }
+
+    #[test]
+    fn determinant_sign() {
+        let rotation = Matrix4D::identity().mul_linear(&Matrix3D::make_rotation(70.0, Vector3D::new(1.0, 2.0, 2.0).normalize()));
+        assert_eq!(rotation.determinant_sign(), 1);
+
+        let reflection = Matrix4D::identity().mul_linear(&Matrix3D::make_reflection(Vector3D::new(0.0, 0.6, 0.8)));
+        assert_eq!(reflection.determinant_sign(), -1);
+
+        let singular = Matrix4D::new(1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0, 8.0, 0.0, 1.0, 0.0, 1.0, 5.0, 0.0, 2.0, 1.0);
+        assert_eq!(singular.determinant_sign(), 0);
+        assert_eq!((Matrix4D::identity() * 0.0).determinant_sign(), 0);
+    }
+
+    #[test]
+    fn projected_sphere_radius() {
+        let (f, near, far) = (1.0 / 30.0_f64.to_radians().tan(), 0.1, 100.0);
+        let projection = Matrix4D::new(f, 0.0, 0.0, 0.0,
+            0.0, f, 0.0, 0.0,
+            0.0, 0.0, (far + near) / (near - far), 2.0 * far * near / (near - far),
+            0.0, 0.0, -1.0, 0.0);
+        let view = Matrix4D::identity().mul_linear(&Matrix3D::make_rotation_y(20.0));
+        let view_projection = projection * view;
+        let direction = Matrix3D::make_rotation_y(-20.0) * Vector3D::new(0.0, 0.0, -1.0);
+
+        let near_sphere = Sphere::new(direction * 10.0, 1.0);
+        let far_sphere = Sphere::new(direction * 20.0, 1.0);
+        let near_radius = view_projection.projected_sphere_radius(&near_sphere, 1080.0);
+        let far_radius = view_projection.projected_sphere_radius(&far_sphere, 1080.0);
+        assert_approx_eq!(near_radius, f * 0.1 * 540.0);
+        assert_approx_eq!(far_radius, near_radius * 0.5);
+    }
+
+    #[test]
+    fn fit_box_to_box() {
+        let src = Aabb::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(1.0, 1.0, 1.0));
+        let dst = Aabb::new(Vector3D::new(2.0, -1.0, 5.0), Vector3D::new(6.0, 1.0, 5.5));
+        let fit = Matrix4D::fit_box_to_box(&src, &dst);
+        let transform = |p: Vector3D| {
+            let v = fit * Vector4D::new(p.x, p.y, p.z, 1.0);
+            Vector3D::new(v.x, v.y, v.z)
+        };
+        assert_eq!(transform(src.min), dst.min);
+        assert_eq!(transform(src.max), dst.max);
+        assert_eq!(transform(Vector3D::new(1.0, 0.0, 1.0)), Vector3D::new(6.0, -1.0, 5.5));
+    }
+
+    #[test]
+    fn blend() {
+        let a = Matrix4D::recompose(Vector3D::new(1.0, 2.0, 3.0), Quaternion::identity(), Vector3D::new(1.0, 1.0, 1.0));
+        let half = 45.0_f64.to_radians();
+        let b = Matrix4D::recompose(Vector3D::new(3.0, 0.0, 3.0),
+            Quaternion::new(0.0, 0.0, half.sin(), half.cos()), Vector3D::new(3.0, 3.0, 1.0));
+        for (t, expected) in [(0.0, a), (1.0, b)] {
+            let m = a.blend(&b, t);
+            for i in 0..4 {
+                for j in 0..4 {
+                    assert_approx_eq!(m[i][j], expected[i][j]);
+                }
+            }
+        }
+
+        let mid = a.blend(&b, 0.5);
+        let (translation, rotation, scale) = mid.decompose();
+        let quarter = 22.5_f64.to_radians();
+        assert_approx_eq!(rotation.z, quarter.sin());
+        assert_approx_eq!(rotation.w, quarter.cos());
+        for (r, e) in std::iter::zip(translation, Vector3D::new(2.0, 1.0, 3.0)) {
+            assert_approx_eq!(r, e);
+        }
+        for (r, e) in std::iter::zip(scale, Vector3D::new(2.0, 2.0, 1.0)) {
+            assert_approx_eq!(r, e);
+        }
+        assert_eq!([mid[3][0], mid[3][1], mid[3][2], mid[3][3]], [0.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn line_reflection() {
+        let z_axis = Line::new(0.0, 0.0, 2.0, 0.0, 0.0, 0.0);
+        let reflection = Matrix4D::make_line_reflection(&z_axis);
+        assert_eq!(reflection * Vector4D::new(1.0, 2.0, 3.0, 1.0), Vector4D::new(-1.0, -2.0, 3.0, 1.0));
+
+        let p = Vector3D::new(1.0, 0.0, 0.0);
+        let v = Vector3D::new(0.0, 0.0, 1.0);
+        let offset = Line::from_vector(v, p.cross(&v));
+        let r = Matrix4D::make_line_reflection(&offset) * Vector4D::new(3.0, 2.0, -1.0, 1.0);
+        for (r, e) in [(r.x, -1.0), (r.y, -2.0), (r.z, -1.0), (r.w, 1.0)] {
+            assert_approx_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn perspective_divide() {
+        let (near, far) = (1.0, 10.0);
+        let projection = Matrix4D::new(1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, (far + near) / (near - far), 2.0 * far * near / (near - far),
+            0.0, 0.0, -1.0, 0.0);
+        let p = projection * Point4D::from(Point3D::new(2.0, -1.0, -4.0));
+        assert_approx_eq!(p.x, 0.5);
+        assert_approx_eq!(p.y, -0.25);
+        assert_approx_eq!(p.z, (11.0 / 9.0 * 4.0 - 20.0 / 9.0) / 4.0);
+
+        let v = projection * Vector4D::new(2.0, -1.0, -4.0, 1.0);
+        assert_eq!((v.x, v.y, v.w), (2.0, -1.0, 4.0));
+    }
+
+    #[test]
+    fn iterator_sum_product() {
+        let chain = [Matrix4D::make_point_reflection(Vector3D::new(1.0, 0.0, 0.0)),
+            Matrix4D::identity().mul_linear(&Matrix3D::make_rotation_y(60.0)),
+            Matrix4D::from_basis(Vector3D::new(2.0, 0.0, 0.0), Vector3D::new(0.0, 1.0, 0.0),
+                Vector3D::new(0.0, 0.0, 1.0), Vector3D::new(0.0, 3.0, -1.0))];
+        assert_eq!(chain.iter().copied().sum::<Matrix4D>(), chain[0] + chain[1] + chain[2]);
+        assert_eq!(chain.iter().copied().product::<Matrix4D>(), chain[0] * chain[1] * chain[2]);
+        assert_eq!(std::iter::empty::<Matrix4D>().product::<Matrix4D>(), Matrix4D::identity());
+    }
+
+    #[test]
+    fn transpose() {
+        let a = Matrix4D::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+        let b = Matrix4D::new(2.0, -1.0, 0.0, 3.0, 0.5, 4.0, -2.0, 1.0, 1.0, 0.0, 3.0, -1.0, -3.0, 2.0, 1.0, 0.25);
+        assert_eq!(a.transpose()[0][3], 13.0);
+        assert_eq!(a.transpose()[3][0], 4.0);
+        assert_eq!((a * b).transpose(), b.transpose() * a.transpose());
+        assert_eq!(a.transpose().transpose(), a);
+    }
+
+    #[test]
+    fn scalar_division() {
+        let m = Matrix4D::new(1.0, -2.0, 3.0, 4.5, 5.0, -6.0, 7.0, 8.25, 9.0, 10.0, -11.0, 12.0, 13.5, 14.0, 15.0, -16.0);
+        assert_eq!(m / 2.0, m * 0.5);
+    }
+
+    #[test]
+    fn identity_inversion() {
+        assert_eq!(Matrix4D::identity().inverse(), Some(Matrix4D::identity()));
+
+        let near_identity = Matrix4D::new(1.0, 0.0, 0.0, 1e-9, 0.0, 1.0 + 1e-9, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+        let inverted = near_identity.inverse().unwrap();
+        assert_ne!(inverted, Matrix4D::identity());
+        assert_approx_eq!(inverted[0][3], -1e-9, 1e-15);
+    }
+
+    #[test]
+    fn row_and_column_major() {
+        let row_major = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0];
+        let column_major = [1.0, 5.0, 9.0, 13.0, 2.0, 6.0, 10.0, 14.0, 3.0, 7.0, 11.0, 15.0, 4.0, 8.0, 12.0, 16.0];
+        let matrix = Matrix4D::from_row_major(&row_major);
+        assert_eq!(matrix, Matrix4D::from_column_major(&column_major));
+        assert_eq!(matrix[0][3], 4.0);
+        assert_eq!(matrix[3][0], 13.0);
+    }
+
+    #[test]
+    fn gltf_array() {
+        let matrix = Matrix4D::new(0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1, 1.2, 1.3, 1.4, 1.5, 1.6);
+        assert_eq!(matrix, Matrix4D::from_gltf_array(&matrix.to_gltf_array()));
+
+        let translation = Matrix4D::new(1.0, 0.0, 0.0, 2.0, 0.0, 1.0, 0.0, 3.0, 0.0, 0.0, 1.0, 4.0, 0.0, 0.0, 0.0, 1.0);
+        let expected = [1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 2.0, 3.0, 4.0, 1.0];
+        assert_eq!(translation.to_gltf_array(), expected);
+    }
+
+    #[test]
+    fn finite_and_nan() {
+        let m = Matrix4D::identity();
+        assert!(m.is_finite());
+        assert!(!m.is_nan());
+
+        let m = Matrix4D::new(1.0, 0.0, 0.0, f64::INFINITY, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+        assert!(!m.is_finite());
+        assert!(!m.is_nan());
+
+        let m = Matrix4D::new(1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, f64::NAN, 0.0, 0.0, 1.0);
+        assert!(!m.is_finite());
+        assert!(m.is_nan());
+    }
+
+    #[test]
+    fn point_reflection() {
+        let center = Vector3D::new(1.0, 2.0, 3.0);
+        let m = Matrix4D::make_point_reflection(center);
+        let p = Vector4D::new(4.0, -1.0, 0.5, 1.0);
+        let q = m * p;
+        assert_eq!(q, Vector4D::new(-2.0, 5.0, 5.5, 1.0));
+        assert_eq!(Vector3D::from(p.midpoint(&q)), center);
+    }
+
+    #[test]
+    fn basis_constructor() {
+        let x = Vector3D::new(0.0, 1.0, 0.0);
+        let y = Vector3D::new(-1.0, 0.0, 0.0);
+        let z = Vector3D::new(0.0, 0.0, 1.0);
+        let origin = Vector3D::new(5.0, 6.0, 7.0);
+        let m = Matrix4D::from_basis(x, y, z, origin);
+        assert_eq!(Vector3D::from(m * Vector4D::new(1.0, 0.0, 0.0, 0.0)), x);
+        assert_eq!(Vector3D::from(m * Vector4D::new(0.0, 1.0, 0.0, 0.0)), y);
+        assert_eq!(Vector3D::from(m * Vector4D::new(0.0, 0.0, 1.0, 0.0)), z);
+        assert_eq!(Vector3D::from(m * Vector4D::new(0.0, 0.0, 0.0, 1.0)), origin);
+    }
+
+    #[test]
+    fn linear_multiplication() {
+        let m = Matrix4D::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 0.0, 0.0, 0.0, 1.0);
+        let r = Matrix3D::make_rotation_y(30.0);
+        let embedded = Matrix4D::new(r[0][0], r[0][1], r[0][2], 0.0,
+            r[1][0], r[1][1], r[1][2], 0.0,
+            r[2][0], r[2][1], r[2][2], 0.0,
+            0.0, 0.0, 0.0, 1.0);
+        let result = m.mul_linear(&r);
+        let expected = m * embedded;
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_approx_eq!(result[i][j], expected[i][j]);
+            }
+        }
+        assert_eq!(result[3], m[3]);
+    }
+
+    #[test]
+    fn slice_product() {
+        let a = Matrix4D::new(0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1, 1.2, 1.3, 1.4, 1.5, 1.6);
+        let b = Matrix4D::new(1.0, 1.0, 1.0, 0.0, 0.0, 3.0, 1.0, 2.0, 1.0, 0.0, 2.0, 1.0, 2.0, 3.0, 1.0, 0.0);
+        let c = Matrix4D::make_point_reflection(Vector3D::new(1.0, 2.0, 3.0));
+        assert_eq!(Matrix4D::product(&[a, b, c]), a * b * c);
+        assert_eq!(Matrix4D::product(&[b]), b);
+        assert_eq!(Matrix4D::product(&[]), Matrix4D::identity());
+    }
+
+    #[test]
+    fn rigid_orthonormalization() {
+        let r = Matrix3D::make_rotation(30.0, Vector3D::new(1.0, 2.0, 2.0).normalize());
+        let x = r * Vector3D::new(1.0, 0.0, 0.0);
+        let y = r * Vector3D::new(0.0, 1.0, 0.0);
+        let z = r * Vector3D::new(0.0, 0.0, 1.0);
+        let t = Vector3D::new(1.0, 2.0, 3.0);
+        let trs = Matrix4D::from_basis(x * 2.0, y * 3.0, z * 4.0, t);
+
+        let rigid = trs.orthonormalized_rigid();
+        let expected = Matrix4D::from_basis(x, y, z, t);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_approx_eq!(rigid[i][j], expected[i][j]);
+            }
+        }
+    }
+
+    #[test]
+    fn cumulative_product() {
+        let translations = [Vector3D::new(1.0, 0.0, 0.0), Vector3D::new(0.0, 2.0, 0.0), Vector3D::new(0.0, 0.0, 3.0)];
+        let chain: Vec<Matrix4D> = translations.iter()
+            .map(|t| Matrix4D::new(1.0, 0.0, 0.0, t.x, 0.0, 1.0, 0.0, t.y, 0.0, 0.0, 1.0, t.z, 0.0, 0.0, 0.0, 1.0))
+            .collect();
+        let products = Matrix4D::cumulative_product(&chain);
+        assert_eq!(products.len(), 3);
+        assert_eq!(products[0] * Vector4D::new(0.0, 0.0, 0.0, 1.0), Vector4D::new(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(products[1] * Vector4D::new(0.0, 0.0, 0.0, 1.0), Vector4D::new(1.0, 2.0, 0.0, 1.0));
+        assert_eq!(products[2] * Vector4D::new(0.0, 0.0, 0.0, 1.0), Vector4D::new(1.0, 2.0, 3.0, 1.0));
+        assert!(Matrix4D::cumulative_product(&[]).is_empty());
+    }
+
+    #[test]
+    fn billboard() {
+        let object = Vector3D::new(1.0, 1.0, 1.0);
+        let camera = Vector3D::new(4.0, 5.0, 6.0);
+        let up = Vector3D::new(0.0, 1.0, 0.0);
+
+        let spherical = Matrix4D::make_billboard(object, camera, up, false);
+        let forward = Vector3D::from(spherical * Vector4D::new(0.0, 0.0, 1.0, 0.0));
+        let expected = (camera - object).normalize();
+        assert_approx_eq!(forward.x, expected.x);
+        assert_approx_eq!(forward.y, expected.y);
+        assert_approx_eq!(forward.z, expected.z);
+        assert_eq!(Vector3D::from(spherical * Vector4D::new(0.0, 0.0, 0.0, 1.0)), object);
+
+        let cylindrical = Matrix4D::make_billboard(object, camera, up, true);
+        let forward = Vector3D::from(cylindrical * Vector4D::new(0.0, 0.0, 1.0, 0.0));
+        let expected = Vector3D::new(3.0, 0.0, 5.0).normalize();
+        assert_approx_eq!(forward.x, expected.x);
+        assert_approx_eq!(forward.y, expected.y);
+        assert_approx_eq!(forward.z, expected.z);
+        assert_eq!(Vector3D::from(cylindrical * Vector4D::new(0.0, 1.0, 0.0, 0.0)), up);
+
+        let overhead = Matrix4D::make_billboard(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(0.0, 5.0, 0.0), up, false);
+        assert!(overhead.is_finite());
+        let forward = Vector3D::from(overhead * Vector4D::new(0.0, 0.0, 1.0, 0.0));
+        assert_approx_eq!(forward.x, 0.0);
+        assert_approx_eq!(forward.y, 1.0);
+        assert_approx_eq!(forward.z, 0.0);
+        assert_approx_eq!(overhead.determinant(), 1.0);
+    }
+
+    #[test]
+    fn linear_determinant() {
+        let r = Matrix3D::make_rotation(70.0, Vector3D::new(2.0, 1.0, -1.0).normalize());
+        let rotation = Matrix4D::from_basis(r * Vector3D::new(1.0, 0.0, 0.0),
+            r * Vector3D::new(0.0, 1.0, 0.0),
+            r * Vector3D::new(0.0, 0.0, 1.0),
+            Vector3D::new(4.0, 5.0, 6.0));
+        assert_approx_eq!(rotation.linear_determinant(), 1.0);
+
+        let scale = Matrix4D::new(2.0, 0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 1.0, 0.0, 0.0, 2.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+        assert_eq!(scale.linear_determinant(), 8.0);
+
+        let reflection = Matrix4D::new(1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, -1.0, 3.0, 0.0, 0.0, 0.0, 1.0);
+        assert_eq!(reflection.linear_determinant(), -1.0);
+    }
+
+    #[test]
+    fn viewport() {
+        let m = Matrix4D::make_viewport(10.0, 20.0, 640.0, 480.0, 0.0, 1.0);
+        assert_eq!(m * Vector4D::new(-1.0, -1.0, -1.0, 1.0), Vector4D::new(10.0, 20.0, 0.0, 1.0));
+        assert_eq!(m * Vector4D::new(1.0, 1.0, 1.0, 1.0), Vector4D::new(650.0, 500.0, 1.0, 1.0));
+        assert_eq!(m * Vector4D::new(-1.0, 1.0, 0.0, 1.0), Vector4D::new(10.0, 500.0, 0.5, 1.0));
+        assert_eq!(m * Vector4D::new(1.0, -1.0, 0.0, 1.0), Vector4D::new(650.0, 20.0, 0.5, 1.0));
+    }
+
+    #[test]
+    fn oblique_clip_plane() {
+        let (n, f) = (1.0, 10.0);
+        let projection = Matrix4D::new(1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, -(f + n) / (f - n), -2.0 * f * n / (f - n),
+            0.0, 0.0, -1.0, 0.0);
+        let clip_plane = Plane::new(0.0, 0.0, -1.0, -3.0);
+        let oblique = projection.with_oblique_clip_plane(clip_plane);
+
+        let on_plane = oblique * Vector4D::new(0.5, 0.5, -3.0, 1.0);
+        assert_approx_eq!(on_plane.z, -on_plane.w);
+
+        let clipped = oblique * Vector4D::new(0.5, 0.5, -2.0, 1.0);
+        assert!(clipped.z < 0.0);
+        assert!(clipped.z < -clipped.w);
+
+        let kept = oblique * Vector4D::new(0.5, 0.5, -5.0, 1.0);
+        assert!(kept.z > -kept.w);
+        assert!(kept.z <= kept.w);
+    }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{Vector3D, Point3D, Transform4D};
+use crate::{Line, Matrix3D, Vector3D, Point3D, Transform4D};
 
 use std::ops::Mul;
 
@@ -66,6 +66,25 @@ impl Plane {
         Vector3D::new(self.x, self.y, self.z)
     }
 
+    pub fn intersect_three(a: &Plane, b: &Plane, c: &Plane) -> Option<Vector3D> {
+        let n = Matrix3D::from_vector(a.get_normal(), b.get_normal(), c.get_normal());
+        let d = Vector3D::new(-a.w, -b.w, -c.w);
+        n.inverse().map(|inv| inv * d)
+    }
+
+    pub fn intersect_plane(&self, other: &Plane) -> Option<Line> {
+        let n1 = self.get_normal();
+        let n2 = other.get_normal();
+
+        let v = n1.cross(&n2);
+        if v.dot(&v) > f64::EPSILON * n1.dot(&n1) * n2.dot(&n2) {
+            let m = n2 * self.w - n1 * other.w;
+            Some(Line::from_vector(v, m))
+        } else {
+            None
+        }
+    }
+
     pub fn new(x: f64, y: f64, z: f64, d: f64) -> Self {
         Self { x, y, z, w: d }
     }
@@ -155,6 +174,48 @@ mod plane_tests {
         assert_eq!(None, intersect_two_planes(f1, f2));
     }
 
+    #[test]
+    fn intersect_three() {
+        let f1 = Plane::new(1.0, 0.0, 0.0, -1.0);
+        let f2 = Plane::new(0.0, 1.0, 0.0, -2.0);
+        let f3 = Plane::new(0.0, 0.0, 1.0, 3.0);
+        let expected = Vector3D::new(1.0, 2.0, -3.0);
+        assert_eq!(expected, Plane::intersect_three(&f1, &f2, &f3).unwrap());
+
+        let f1 = Plane::new(1.0, 0.0, 0.0, 5.0);
+        let f2 = Plane::new(1.0, 0.0, 0.0, 0.0);
+        let f3 = Plane::new(0.0, 0.0, 1.0, 0.0);
+        assert_eq!(None, Plane::intersect_three(&f1, &f2, &f3));
+    }
+
+    #[test]
+    fn plane_plane_intersection() {
+        let xy = Plane::new(0.0, 0.0, 1.0, 0.0);
+        let xz = Plane::new(0.0, 1.0, 0.0, 0.0);
+        let expected = Line::new(-1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        assert_eq!(expected, xy.intersect_plane(&xz).unwrap());
+
+        let f1 = Plane::new(0.0, 0.0, 1.0, -1.0);
+        let f2 = Plane::new(0.0, 1.0, 0.0, -2.0);
+        let p = Vector3D::new(0.0, 2.0, 1.0);
+        let l = f1.intersect_plane(&f2).unwrap();
+        assert_eq!(p.cross(&l.direction), l.moment);
+
+        let f1 = Plane::new(1.0, 0.0, 0.0, 5.0);
+        let f2 = Plane::new(1.0, 0.0, 0.0, 0.0);
+        assert_eq!(None, f1.intersect_plane(&f2));
+
+        let f1 = Plane::new(1.0e-5, 0.0, 0.0, 2.0e-5);
+        let f2 = Plane::new(0.0, 1.0e-5, 0.0, -1.0e-5);
+        let l = f1.intersect_plane(&f2).unwrap();
+        let scale = l.direction.magnitude();
+        let direction = l.direction / scale;
+        assert_approx_eq!(direction.z, 1.0);
+        for (r, e) in std::iter::zip(Vector3D::new(-2.0, 1.0, 0.0).cross(&direction), l.moment / scale) {
+            assert_approx_eq!(r, e);
+        }
+    }
+
     #[test]
     fn plane_transformation() {
         let f1 = Plane::new(1.0, 1.0, 0.0, 5.0);
@@ -9,6 +9,14 @@ pub struct Point3D {
     pub z: f64,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Point4D {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
 pub fn distance_point_line(q: Point3D, p: Point3D, v: Vector3D) -> f64 {
     let a = (q - p).cross(&v);
     (a.dot(&a) / v.dot(&v)).sqrt()
@@ -45,6 +53,22 @@ impl Point3D {
     }
 }
 
+impl Point4D {
+    pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub fn to_point3d(&self) -> Point3D {
+        Point3D::new(self.x / self.w, self.y / self.w, self.z / self.w)
+    }
+}
+
+impl From<Point3D> for Point4D {
+    fn from(p: Point3D) -> Self {
+        Point4D::new(p.x, p.y, p.z, 1.0)
+    }
+}
+
 impl Add<Vector3D> for Point3D {
     type Output = Self;
     fn add(self, rhs: Vector3D) -> Self::Output {
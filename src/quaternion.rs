@@ -0,0 +1,278 @@
+use crate::{Matrix3D, Vector3D};
+
+use std::ops::{Add, Mul, Neg};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl Quaternion {
+    pub fn conjugate(&self) -> Quaternion {
+        Quaternion::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    pub fn dot(&self, rhs: &Quaternion) -> f64 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    pub fn from_matrix3d(m: &Matrix3D) -> Quaternion {
+        let (m00, m11, m22) = (m[0][0], m[1][1], m[2][2]);
+        let sum = m00 + m11 + m22;
+        if sum > 0.0 {
+            let w = (sum + 1.0).sqrt() * 0.5;
+            let f = 0.25 / w;
+            Quaternion::new((m[2][1] - m[1][2]) * f, (m[0][2] - m[2][0]) * f, (m[1][0] - m[0][1]) * f, w)
+        } else if m00 > m11 && m00 > m22 {
+            let x = (m00 - m11 - m22 + 1.0).sqrt() * 0.5;
+            let f = 0.25 / x;
+            Quaternion::new(x, (m[1][0] + m[0][1]) * f, (m[0][2] + m[2][0]) * f, (m[2][1] - m[1][2]) * f)
+        } else if m11 > m22 {
+            let y = (m11 - m00 - m22 + 1.0).sqrt() * 0.5;
+            let f = 0.25 / y;
+            Quaternion::new((m[1][0] + m[0][1]) * f, y, (m[2][1] + m[1][2]) * f, (m[0][2] - m[2][0]) * f)
+        } else {
+            let z = (m22 - m00 - m11 + 1.0).sqrt() * 0.5;
+            let f = 0.25 / z;
+            Quaternion::new((m[0][2] + m[2][0]) * f, (m[2][1] + m[1][2]) * f, z, (m[1][0] - m[0][1]) * f)
+        }
+    }
+
+    pub fn from_rotation_between(from: Vector3D, to: Vector3D) -> Quaternion {
+        let f = from.normalize();
+        let h = f + to.normalize();
+        if h.magnitude() <= 1.0e-9 {
+            let axis = f.any_perpendicular().normalize();
+            return Quaternion::new(axis.x, axis.y, axis.z, 0.0);
+        }
+        let h = h.normalize();
+        let axis = f.cross(&h);
+        Quaternion::new(axis.x, axis.y, axis.z, f.dot(&h))
+    }
+
+    pub fn identity() -> Self {
+        Quaternion::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Maps local +Z onto `forward`, matching `Matrix3D::make_look_rotation`.
+    pub fn look_rotation(forward: Vector3D, up: Vector3D) -> Quaternion {
+        Quaternion::from_matrix3d(&Matrix3D::make_look_rotation(forward, up))
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub fn normalize(&self) -> Quaternion {
+        *self * (1.0 / self.magnitude())
+    }
+
+    pub fn rotate(&self, v: Vector3D) -> Vector3D {
+        let u = Vector3D::new(self.x, self.y, self.z);
+        let t = u.cross(&v) * 2.0;
+        v + t * self.w + u.cross(&t)
+    }
+
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Quaternion {
+        if self.dot(other) < 0.0 {
+            self.interpolate(&-*other, t)
+        } else {
+            self.interpolate(other, t)
+        }
+    }
+
+    pub fn slerp_long(&self, other: &Quaternion, t: f64) -> Quaternion {
+        if self.dot(other) > 0.0 {
+            self.interpolate(&-*other, t)
+        } else {
+            self.interpolate(other, t)
+        }
+    }
+
+    pub fn swing_twist(&self, axis: Vector3D) -> (Quaternion, Quaternion) {
+        let a = axis.normalize();
+        let p = a * Vector3D::new(self.x, self.y, self.z).dot(&a);
+        let twist = Quaternion::new(p.x, p.y, p.z, self.w);
+        let twist = if twist.magnitude() <= f64::EPSILON {
+            Quaternion::identity()
+        } else {
+            twist.normalize()
+        };
+        (*self * twist.conjugate(), twist)
+    }
+
+    pub fn to_matrix3d(&self) -> Matrix3D {
+        let x2 = self.x * self.x;
+        let y2 = self.y * self.y;
+        let z2 = self.z * self.z;
+        let xy = self.x * self.y;
+        let xz = self.x * self.z;
+        let yz = self.y * self.z;
+        let wx = self.w * self.x;
+        let wy = self.w * self.y;
+        let wz = self.w * self.z;
+
+        Matrix3D::new(
+            1.0 - 2.0 * (y2 + z2), 2.0 * (xy - wz), 2.0 * (xz + wy),
+            2.0 * (xy + wz), 1.0 - 2.0 * (x2 + z2), 2.0 * (yz - wx),
+            2.0 * (xz - wy), 2.0 * (yz + wx), 1.0 - 2.0 * (x2 + y2))
+    }
+
+    fn interpolate(&self, other: &Quaternion, t: f64) -> Quaternion {
+        let cos = self.dot(other).clamp(-1.0, 1.0);
+        let theta = cos.acos();
+        let sin = theta.sin();
+        if sin.abs() > 1.0e-6 {
+            (*self * ((1.0 - t) * theta).sin() + *other * (t * theta).sin()) * (1.0 / sin)
+        } else {
+            (*self * (1.0 - t) + *other * t).normalize()
+        }
+    }
+}
+
+impl Add<Self> for Quaternion {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Quaternion::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z, self.w + rhs.w)
+    }
+}
+
+impl Mul<f64> for Quaternion {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Quaternion::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+impl Mul<Self> for Quaternion {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Quaternion::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z)
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Quaternion::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+#[cfg(test)]
+mod quaternion_tests {
+    use super::*;
+
+    use assert_approx_eq::assert_approx_eq;
+
+    fn rotation_z(degrees: f64) -> Quaternion {
+        let half = degrees.to_radians() * 0.5;
+        Quaternion::new(0.0, 0.0, half.sin(), half.cos())
+    }
+
+    fn angle_between(a: &Quaternion, b: &Quaternion) -> f64 {
+        2.0 * a.dot(b).abs().clamp(-1.0, 1.0).acos().to_degrees()
+    }
+
+    #[test]
+    fn look_rotation() {
+        let forward = Vector3D::new(-1.0, 0.5, 2.0);
+        let up = Vector3D::new(0.0, 1.0, 0.0);
+        let q = Quaternion::look_rotation(forward, up);
+        for (r, e) in std::iter::zip(q.rotate(Vector3D::new(0.0, 0.0, 1.0)), forward.normalize()) {
+            assert_approx_eq!(r, e);
+        }
+        for (r, e) in std::iter::zip(q.to_matrix3d(), Matrix3D::make_look_rotation(forward, up)) {
+            assert_approx_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn rotation_between() {
+        let x = Vector3D::new(1.0, 0.0, 0.0);
+        let y = Vector3D::new(0.0, 2.0, 0.0);
+        let q = Quaternion::from_rotation_between(x, y);
+        assert_approx_eq!(q.magnitude(), 1.0);
+        assert_approx_eq!(q.z, rotation_z(90.0).z);
+        for (r, e) in std::iter::zip(q.rotate(x), Vector3D::new(0.0, 1.0, 0.0)) {
+            assert_approx_eq!(r, e);
+        }
+
+        let from = Vector3D::new(1.0, 2.0, 3.0).normalize();
+        let q = Quaternion::from_rotation_between(from, -from);
+        assert_approx_eq!(q.magnitude(), 1.0);
+        for (r, e) in std::iter::zip(q.rotate(from), -from) {
+            assert_approx_eq!(r, e);
+        }
+    }
+
+    fn assert_same_rotation(a: &Quaternion, b: &Quaternion) {
+        assert_approx_eq!(a.dot(b).abs(), 1.0);
+    }
+
+    #[test]
+    fn swing_twist() {
+        let z = Vector3D::new(0.0, 0.0, 1.0);
+        let twist_only = rotation_z(70.0);
+        let (swing, twist) = twist_only.swing_twist(z);
+        assert_same_rotation(&swing, &Quaternion::identity());
+        assert_same_rotation(&twist, &twist_only);
+
+        let half = 25.0_f64.to_radians();
+        let swing_only = Quaternion::new(half.sin(), 0.0, 0.0, half.cos());
+        let (swing, twist) = swing_only.swing_twist(z);
+        assert_same_rotation(&swing, &swing_only);
+        assert_same_rotation(&twist, &Quaternion::identity());
+
+        let q = swing_only * twist_only;
+        let (swing, twist) = q.swing_twist(z * 2.0);
+        assert_same_rotation(&(swing * twist), &q);
+        assert_same_rotation(&twist, &twist_only);
+    }
+
+    #[test]
+    fn slerp() {
+        let q0 = Quaternion::identity();
+        let q1 = rotation_z(90.0);
+        assert_eq!(q0.slerp(&q1, 0.0), q0);
+        let q = q0.slerp(&q1, 0.5);
+        assert_approx_eq!(q.z, rotation_z(45.0).z);
+        assert_approx_eq!(q.w, rotation_z(45.0).w);
+        assert_approx_eq!(angle_between(&q0, &q), 45.0);
+        assert_approx_eq!(angle_between(&q, &q1), 45.0);
+    }
+
+    #[test]
+    fn slerp_long() {
+        let q0 = Quaternion::identity();
+        let q1 = rotation_z(90.0);
+        let q = q0.slerp_long(&q1, 0.5);
+        assert_approx_eq!(q.z, rotation_z(-135.0).z);
+        assert_approx_eq!(q.w, rotation_z(-135.0).w);
+        assert_approx_eq!(angle_between(&q0, &q), 135.0);
+        assert_approx_eq!(angle_between(&q, &q1), 135.0);
+
+        let end = q0.slerp_long(&q1, 1.0);
+        assert_approx_eq!(angle_between(&end, &q1), 0.0);
+    }
+
+    #[test]
+    fn matrix_conversion() {
+        let q = rotation_z(90.0);
+        let m = q.to_matrix3d();
+        let expected = Matrix3D::make_rotation_z(90.0);
+        for (r, e) in std::iter::zip(m, expected) {
+            assert_approx_eq!(r, e);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,124 @@
+use crate::Vector3D;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray {
+    pub origin: Vector3D,
+    pub direction: Vector3D,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Segment {
+    pub start: Vector3D,
+    pub end: Vector3D,
+}
+
+impl Ray {
+    pub fn closest_points_to_segment(&self, seg: &Segment) -> (Vector3D, Vector3D, f64) {
+        let d1 = self.direction;
+        let d2 = seg.end - seg.start;
+        let r = self.origin - seg.start;
+
+        let a = d1.dot(&d1);
+        let b = d1.dot(&d2);
+        let c = d1.dot(&r);
+        let e = d2.dot(&d2);
+        let f = d2.dot(&r);
+
+        let (t, s) = if a <= f64::EPSILON && e <= f64::EPSILON {
+            (0.0, 0.0)
+        } else if a <= f64::EPSILON {
+            (0.0, (f / e).clamp(0.0, 1.0))
+        } else if e <= f64::EPSILON {
+            ((-c / a).max(0.0), 0.0)
+        } else {
+            let det = a * e - b * b;
+            let t = if det > f64::EPSILON {
+                ((b * f - c * e) / det).max(0.0)
+            } else {
+                0.0
+            };
+
+            let s = (b * t + f) / e;
+            if s < 0.0 {
+                ((-c / a).max(0.0), 0.0)
+            } else if s > 1.0 {
+                (((b - c) / a).max(0.0), 1.0)
+            } else {
+                (t, s)
+            }
+        };
+
+        let p = self.origin + d1 * t;
+        let q = seg.start + d2 * s;
+        (p, q, (q - p).magnitude())
+    }
+
+    pub fn new(origin: Vector3D, direction: Vector3D) -> Self {
+        Self { origin, direction }
+    }
+}
+
+impl Segment {
+    pub fn new(start: Vector3D, end: Vector3D) -> Self {
+        Self { start, end }
+    }
+}
+
+#[cfg(test)]
+mod ray_tests {
+    use super::*;
+
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn closest_points_skew() {
+        let ray = Ray::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(1.0, 0.0, 0.0));
+        let seg = Segment::new(Vector3D::new(2.0, -1.0, 1.0), Vector3D::new(2.0, 1.0, 1.0));
+        let (p, q, d) = ray.closest_points_to_segment(&seg);
+        assert_eq!(p, Vector3D::new(2.0, 0.0, 0.0));
+        assert_eq!(q, Vector3D::new(2.0, 0.0, 1.0));
+        assert_approx_eq!(d, 1.0);
+
+        let seg = Segment::new(Vector3D::new(2.0, 1.0, 1.0), Vector3D::new(2.0, 3.0, 1.0));
+        let (p, q, d) = ray.closest_points_to_segment(&seg);
+        assert_eq!(p, Vector3D::new(2.0, 0.0, 0.0));
+        assert_eq!(q, seg.start);
+        assert_approx_eq!(d, 2.0_f64.sqrt());
+    }
+
+    #[test]
+    fn closest_points_parallel() {
+        let ray = Ray::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(1.0, 0.0, 0.0));
+        let seg = Segment::new(Vector3D::new(1.0, 1.0, 0.0), Vector3D::new(3.0, 1.0, 0.0));
+        let (p, q, d) = ray.closest_points_to_segment(&seg);
+        assert_eq!(p, Vector3D::new(1.0, 0.0, 0.0));
+        assert_eq!(q, Vector3D::new(1.0, 1.0, 0.0));
+        assert_approx_eq!(d, 1.0);
+    }
+
+    #[test]
+    fn closest_points_intersecting() {
+        let ray = Ray::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(1.0, 1.0, 0.0));
+        let seg = Segment::new(Vector3D::new(2.0, 0.0, 0.0), Vector3D::new(0.0, 2.0, 0.0));
+        let (p, q, d) = ray.closest_points_to_segment(&seg);
+        assert_eq!(p, Vector3D::new(1.0, 1.0, 0.0));
+        assert_eq!(q, Vector3D::new(1.0, 1.0, 0.0));
+        assert_approx_eq!(d, 0.0);
+    }
+
+    #[test]
+    fn closest_points_zero_direction() {
+        let ray = Ray::new(Vector3D::new(1.0, 2.0, 0.0), Vector3D::new(0.0, 0.0, 0.0));
+        let seg = Segment::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(4.0, 0.0, 0.0));
+        let (p, q, d) = ray.closest_points_to_segment(&seg);
+        assert_eq!(p, ray.origin);
+        assert_eq!(q, Vector3D::new(1.0, 0.0, 0.0));
+        assert_approx_eq!(d, 2.0);
+
+        let point = Segment::new(Vector3D::new(1.0, 5.0, 0.0), Vector3D::new(1.0, 5.0, 0.0));
+        let (p, q, d) = ray.closest_points_to_segment(&point);
+        assert_eq!(p, ray.origin);
+        assert_eq!(q, point.start);
+        assert_approx_eq!(d, 3.0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use crate::{Aabb, Matrix4D, Vector3D, Vector4D};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sphere {
+    pub center: Vector3D,
+    pub radius: f64,
+}
+
+impl Sphere {
+    pub fn new(center: Vector3D, radius: f64) -> Self {
+        Self { center, radius }
+    }
+
+    pub fn transformed_aabb(&self, m: &Matrix4D) -> Aabb {
+        let c = *m * Vector4D::new(self.center.x, self.center.y, self.center.z, 1.0);
+        let c = Vector3D::new(c.x, c.y, c.z);
+        let row = |i: usize| Vector3D::new(m[i][0], m[i][1], m[i][2]).magnitude() * self.radius;
+        let half = Vector3D::new(row(0), row(1), row(2));
+        Aabb::new(c - half, c + half)
+    }
+}
+
+#[cfg(test)]
+mod sphere_tests {
+    use super::*;
+    use crate::Matrix3D;
+
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn transformed_aabb() {
+        let sphere = Sphere::new(Vector3D::new(1.0, 0.0, 0.0), 2.0);
+        let uniform = Matrix4D::fit_box_to_box(
+            &Aabb::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(1.0, 1.0, 1.0)),
+            &Aabb::new(Vector3D::new(5.0, 0.0, 0.0), Vector3D::new(8.0, 3.0, 3.0)));
+        let aabb = sphere.transformed_aabb(&uniform);
+        assert_eq!(aabb, Aabb::new(Vector3D::new(2.0, -6.0, -6.0), Vector3D::new(14.0, 6.0, 6.0)));
+
+        let anisotropic = Matrix4D::identity()
+            .mul_linear(&(Matrix3D::make_rotation_z(90.0) * Matrix3D::make_scale(3.0, 1.0, 0.5)));
+        let aabb = sphere.transformed_aabb(&anisotropic);
+        for (r, e) in std::iter::zip(aabb.min, Vector3D::new(-2.0, -3.0, -1.0)) {
+            assert_approx_eq!(r, e);
+        }
+        for (r, e) in std::iter::zip(aabb.max, Vector3D::new(2.0, 9.0, 1.0)) {
+            assert_approx_eq!(r, e);
+        }
+    }
+}
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{vector::Vector3D, Vector4D};
+use crate::{vector::Vector3D, Matrix3D, Vector4D};
 use crate::point::Point3D;
 
 use std::ops::{Index, Mul};
@@ -9,6 +9,7 @@ pub struct Transform4D {
 }
 
 impl Transform4D {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(n00: f64, n01: f64, n02: f64, n03: f64,
         n10: f64, n11: f64, n12: f64, n13: f64,
         n20: f64, n21: f64, n22: f64, n23: f64) -> Self {
@@ -65,6 +66,17 @@ impl Transform4D {
         let n4 = Vector4D::new(p.x, p.y, p.z, 1.0);
         Self { n: [n1, n2, n3, n4] }
     }
+
+    pub fn transform_normal(&self, n: Vector3D) -> Vector3D {
+        let h0 = Vector3D::from(self[0]);
+        let h1 = Vector3D::from(self[1]);
+        let h2 = Vector3D::from(self[2]);
+
+        let h1xh2 = h1.cross(&h2);
+        let cofactor = Matrix3D::from_vector(h1xh2, h2.cross(&h0), h0.cross(&h1));
+        let sign = h0.dot(&h1xh2).signum();
+        (cofactor * n * sign).normalize()
+    }
 }
 
 impl Index<usize> for Transform4D {
@@ -200,4 +212,21 @@ mod transform4d_tests {
         assert_eq!(product[3][2], identity_matrix[3][2]);
         assert_eq!(product[3][3], identity_matrix[3][3]);
     }
+
+    #[test]
+    fn normal_transformation() {
+        let t = Transform4D::new(2.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0, 1.0, 3.0);
+        let n = Vector3D::new(1.0, 1.0, 0.0).normalize();
+        let tangent = Vector3D::new(1.0, -1.0, 0.0);
+        let transformed_tangent = t * tangent;
+
+        let transformed_n = t.transform_normal(n);
+        assert_approx_eq!(transformed_n.magnitude(), 1.0);
+        assert_approx_eq!(transformed_n.dot(&transformed_tangent), 0.0);
+        assert!((t * n).dot(&transformed_tangent).abs() > 0.1);
+
+        let reflection = Transform4D::new(-1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0);
+        let n = Vector3D::new(1.0, 0.0, 0.0);
+        assert_eq!(reflection.transform_normal(n), Vector3D::new(-1.0, 0.0, 0.0));
+    }
 }
\ No newline at end of file
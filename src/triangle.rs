@@ -0,0 +1,278 @@
+use crate::{Matrix3D, Vector2D, Vector3D};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Triangle {
+    pub a: Vector3D,
+    pub b: Vector3D,
+    pub c: Vector3D,
+}
+
+pub fn compute_tangent_basis(p0: Vector3D, p1: Vector3D, p2: Vector3D,
+    uv0: Vector2D, uv1: Vector2D, uv2: Vector2D) -> (Vector3D, Vector3D) {
+    let e1 = p1 - p0;
+    let e2 = p2 - p0;
+    let d1 = uv1 - uv0;
+    let d2 = uv2 - uv0;
+
+    let det = d1.x * d2.y - d2.x * d1.y;
+    if det.abs() > f64::EPSILON {
+        let r = 1.0 / det;
+        let t = (e1 * d2.y - e2 * d1.y) * r;
+        let b = (e2 * d1.x - e1 * d2.x) * r;
+        (t, b)
+    } else {
+        (Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(0.0, 0.0, 0.0))
+    }
+}
+
+pub fn compute_vertex_normals(positions: &[Vector3D], indices: &[[usize; 3]]) -> Vec<Vector3D> {
+    let mut normals = vec![Vector3D::new(0.0, 0.0, 0.0); positions.len()];
+    for &[i, j, k] in indices {
+        let n = (positions[j] - positions[i]).cross(&(positions[k] - positions[i]));
+        normals[i] = normals[i] + n;
+        normals[j] = normals[j] + n;
+        normals[k] = normals[k] + n;
+    }
+    normals.iter().map(|n| if n.magnitude() > f64::EPSILON { n.normalize() } else { *n }).collect()
+}
+
+pub fn mesh_mass_properties(positions: &[Vector3D], indices: &[[usize; 3]], density: f64) -> (f64, Vector3D, Matrix3D) {
+    // Sums signed tetrahedra formed with the origin; `canonical` is the second moment of the
+    // unit tetrahedron (0, e1, e2, e3).
+    let canonical = Matrix3D::new(2.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 2.0) / 120.0;
+    let mut volume = 0.0;
+    let mut weighted_center = Vector3D::new(0.0, 0.0, 0.0);
+    let mut covariance = Matrix3D::identity() * 0.0;
+    for &[i, j, k] in indices {
+        let (a, b, c) = (positions[i], positions[j], positions[k]);
+        let rows = Matrix3D::from_vector(a, b, c);
+        let det = rows.determinant();
+        volume += det / 6.0;
+        weighted_center = weighted_center + (a + b + c) * (det / 24.0);
+        covariance = covariance + rows.transpose() * canonical * rows * det;
+    }
+    if volume.abs() <= f64::EPSILON {
+        return (0.0, Vector3D::new(0.0, 0.0, 0.0), Matrix3D::identity() * 0.0);
+    }
+
+    let centroid = weighted_center / volume;
+    let c = centroid;
+    let offset = Matrix3D::new(c.x * c.x, c.x * c.y, c.x * c.z,
+        c.y * c.x, c.y * c.y, c.y * c.z,
+        c.z * c.x, c.z * c.y, c.z * c.z) * volume;
+    let covariance = (covariance - offset) * density;
+    let trace = covariance[0][0] + covariance[1][1] + covariance[2][2];
+    (volume * density, centroid, Matrix3D::identity() * trace - covariance)
+}
+
+impl Triangle {
+    pub fn area(&self) -> f64 {
+        (self.b - self.a).cross(&(self.c - self.a)).magnitude() * 0.5
+    }
+
+    pub fn circumcenter(&self) -> Option<Vector3D> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let n = ab.cross(&ac);
+        let n2 = n.dot(&n);
+        if n2 <= f64::EPSILON * ab.dot(&ab) * ac.dot(&ac) {
+            return None;
+        }
+        let offset = (n.cross(&ab) * ac.dot(&ac) + ac.cross(&n) * ab.dot(&ab)) / (2.0 * n2);
+        Some(self.a + offset)
+    }
+
+    pub fn intersect_ray(&self, origin: Vector3D, direction: Vector3D) -> Option<(f64, f64, f64)> {
+        self.moller_trumbore(origin, direction, false)
+    }
+
+    pub fn intersect_ray_culled(&self, origin: Vector3D, direction: Vector3D) -> Option<(f64, f64, f64)> {
+        self.moller_trumbore(origin, direction, true)
+    }
+
+    fn moller_trumbore(&self, origin: Vector3D, direction: Vector3D, cull: bool) -> Option<(f64, f64, f64)> {
+        let e1 = self.b - self.a;
+        let e2 = self.c - self.a;
+        let p = direction.cross(&e2);
+        let det = e1.dot(&p);
+        if (cull && det <= f64::EPSILON) || det.abs() <= f64::EPSILON {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+        let s = origin - self.a;
+        let u = s.dot(&p) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = s.cross(&e1);
+        let v = direction.dot(&q) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+        let t = e2.dot(&q) * inv_det;
+        if t < 0.0 {
+            return None;
+        }
+        Some((t, u, v))
+    }
+
+    pub fn new(a: Vector3D, b: Vector3D, c: Vector3D) -> Self {
+        Self { a, b, c }
+    }
+
+    pub fn normal(&self) -> Vector3D {
+        let n = (self.b - self.a).cross(&(self.c - self.a));
+        let magnitude = n.magnitude();
+        if magnitude > f64::EPSILON {
+            n / magnitude
+        } else {
+            Vector3D::new(0.0, 0.0, 0.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod triangle_tests {
+    use super::*;
+
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn area() {
+        let t = Triangle::new(Vector3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(1.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0));
+        assert_approx_eq!(t.area(), 0.5);
+
+        let degenerate = Triangle::new(Vector3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(1.0, 1.0, 1.0),
+            Vector3D::new(2.0, 2.0, 2.0));
+        assert_eq!(degenerate.area(), 0.0);
+    }
+
+    #[test]
+    fn circumcenter() {
+        let t = Triangle::new(Vector3D::new(1.0, 1.0, 1.0),
+            Vector3D::new(4.0, 1.0, 1.0),
+            Vector3D::new(1.0, 5.0, 1.0));
+        let center = t.circumcenter().unwrap();
+        for (r, e) in std::iter::zip(center, t.b.midpoint(&t.c)) {
+            assert_approx_eq!(r, e);
+        }
+
+        let degenerate = Triangle::new(Vector3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(1.0, 1.0, 1.0),
+            Vector3D::new(2.0, 2.0, 2.0));
+        assert_eq!(degenerate.circumcenter(), None);
+
+        let small = Triangle::new(Vector3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(1.0e-4, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0e-4, 0.0));
+        let center = small.circumcenter().unwrap();
+        for (r, e) in std::iter::zip(center, small.b.midpoint(&small.c)) {
+            assert_approx_eq!(r, e, 1.0e-18);
+        }
+    }
+
+    #[test]
+    fn intersect_ray() {
+        let t = Triangle::new(Vector3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(1.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0));
+        let down = Vector3D::new(0.0, 0.0, -1.0);
+
+        let (distance, u, v) = t.intersect_ray(Vector3D::new(0.25, 0.5, 2.0), down).unwrap();
+        assert_approx_eq!(distance, 2.0);
+        assert_approx_eq!(u, 0.25);
+        assert_approx_eq!(v, 0.5);
+
+        assert_eq!(t.intersect_ray(Vector3D::new(1.0, 1.0, 2.0), down), None);
+        assert_eq!(t.intersect_ray(Vector3D::new(0.25, 0.25, 2.0), Vector3D::new(1.0, 0.0, 0.0)), None);
+        assert_eq!(t.intersect_ray(Vector3D::new(0.25, 0.25, -2.0), down), None);
+
+        let up = Vector3D::new(0.0, 0.0, 1.0);
+        assert!(t.intersect_ray(Vector3D::new(0.25, 0.25, -2.0), up).is_some());
+        assert_eq!(t.intersect_ray_culled(Vector3D::new(0.25, 0.25, -2.0), up), None);
+        assert!(t.intersect_ray_culled(Vector3D::new(0.25, 0.25, 2.0), down).is_some());
+    }
+
+    #[test]
+    fn normal() {
+        let t = Triangle::new(Vector3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(1.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0));
+        assert_eq!(t.normal(), Vector3D::new(0.0, 0.0, 1.0));
+
+        let flipped = Triangle::new(t.a, t.c, t.b);
+        assert_eq!(flipped.normal(), Vector3D::new(0.0, 0.0, -1.0));
+
+        let degenerate = Triangle::new(Vector3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(1.0, 1.0, 1.0),
+            Vector3D::new(2.0, 2.0, 2.0));
+        assert_eq!(degenerate.normal(), Vector3D::new(0.0, 0.0, 0.0));
+    }
+
+    fn cube() -> (Vec<Vector3D>, Vec<[usize; 3]>) {
+        let positions = (0..8).map(|i| Vector3D::new(
+            if i & 1 == 0 { -1.0 } else { 1.0 },
+            if i & 2 == 0 { -1.0 } else { 1.0 },
+            if i & 4 == 0 { -1.0 } else { 1.0 })).collect();
+        let indices = vec![[0, 2, 1], [1, 2, 3], [4, 5, 6], [5, 7, 6], [0, 1, 5], [0, 5, 4],
+            [2, 6, 7], [2, 7, 3], [0, 4, 6], [0, 6, 2], [1, 3, 7], [1, 7, 5]];
+        (positions, indices)
+    }
+
+    #[test]
+    fn vertex_normals() {
+        let quad = [Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(1.0, 0.0, 0.0),
+            Vector3D::new(1.0, 1.0, 0.0), Vector3D::new(0.0, 1.0, 0.0)];
+        for n in compute_vertex_normals(&quad, &[[0, 1, 2], [0, 2, 3]]) {
+            assert_eq!(n, Vector3D::new(0.0, 0.0, 1.0));
+        }
+
+        let (positions, indices) = cube();
+        let normals = compute_vertex_normals(&positions, &indices);
+        for (p, n) in std::iter::zip(&positions, &normals) {
+            assert_approx_eq!(n.magnitude(), 1.0);
+            assert_eq!(n.signum(), p.signum());
+            assert!(n.dot(&p.normalize()) > 0.9);
+        }
+    }
+
+    #[test]
+    fn mass_properties() {
+        let (positions, indices) = cube();
+        let density = 1.5;
+        let (mass, centroid, inertia) = mesh_mass_properties(&positions, &indices, density);
+        assert_approx_eq!(mass, 8.0 * density);
+        for (r, e) in std::iter::zip(centroid, Vector3D::new(0.0, 0.0, 0.0)) {
+            assert_approx_eq!(r, e);
+        }
+        for (r, e) in std::iter::zip(inertia, Matrix3D::box_inertia(mass, Vector3D::new(2.0, 2.0, 2.0))) {
+            assert_approx_eq!(r, e);
+        }
+
+        let shifted: Vec<Vector3D> = positions.iter().map(|&p| p + Vector3D::new(3.0, -1.0, 2.0)).collect();
+        let (_, centroid, shifted_inertia) = mesh_mass_properties(&shifted, &indices, density);
+        for (r, e) in std::iter::zip(centroid, Vector3D::new(3.0, -1.0, 2.0)) {
+            assert_approx_eq!(r, e);
+        }
+        for (r, e) in std::iter::zip(shifted_inertia, inertia) {
+            assert_approx_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn tangent_basis() {
+        let p = [Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(2.0, 0.0, 0.0),
+            Vector3D::new(2.0, 2.0, 0.0), Vector3D::new(0.0, 2.0, 0.0)];
+        let uv = [Vector2D::new(0.0, 0.0), Vector2D::new(1.0, 0.0),
+            Vector2D::new(1.0, 1.0), Vector2D::new(0.0, 1.0)];
+        for [i, j, k] in [[0, 1, 2], [0, 2, 3]] {
+            let (t, b) = compute_tangent_basis(p[i], p[j], p[k], uv[i], uv[j], uv[k]);
+            assert_eq!(t.normalize(), Vector3D::new(1.0, 0.0, 0.0));
+            assert_eq!(b.normalize(), Vector3D::new(0.0, 1.0, 0.0));
+        }
+    }
+}
\ No newline at end of file
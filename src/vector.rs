@@ -1,8 +1,52 @@
-use crate::Transform4D;
+use crate::{Matrix3D, Plane, Transform4D};
 
+use std::fmt::Display;
 use std::ops::{Add, Div, Index, Mul, Neg, Sub};
 use std::convert::From;
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vector2D {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+    Collinear,
+}
+
+pub fn signed_area_2d(a: Vector2D, b: Vector2D, c: Vector2D) -> f64 {
+    let ab = b - a;
+    let ac = c - a;
+    (ab.x * ac.y - ab.y * ac.x) * 0.5
+}
+
+pub fn winding(a: Vector2D, b: Vector2D, c: Vector2D) -> Winding {
+    let area = signed_area_2d(a, b, c);
+    if area > f64::EPSILON {
+        Winding::CounterClockwise
+    } else if area < -f64::EPSILON {
+        Winding::Clockwise
+    } else {
+        Winding::Collinear
+    }
+}
+
+impl Vector2D {
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Sub<Self> for Vector2D {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector2D::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Vector3D {
     pub x: f64,
@@ -10,22 +54,207 @@ pub struct Vector3D {
     pub z: f64,
 }
 
+pub fn are_coplanar(a: Vector3D, b: Vector3D, c: Vector3D, d: Vector3D, epsilon: f64) -> bool {
+    let triple = (b - a).cross(&(c - a)).dot(&(d - a));
+    triple.abs() <= epsilon
+}
+
+fn srgb_channel_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_channel_to_srgb(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+pub fn point_in_convex_polygon(point: Vector3D, vertices: &[Vector3D], normal: Vector3D) -> bool {
+    if vertices.len() < 3 {
+        return false;
+    }
+    let (mut positive, mut negative) = (false, false);
+    for i in 0..vertices.len() {
+        let edge = vertices[(i + 1) % vertices.len()] - vertices[i];
+        let side = edge.cross(&(point - vertices[i])).dot(&normal);
+        if side > f64::EPSILON {
+            positive = true;
+        } else if side < -f64::EPSILON {
+            negative = true;
+        }
+    }
+    !(positive && negative)
+}
+
+pub fn polygon_area(vertices: &[Vector3D]) -> f64 {
+    if vertices.len() < 3 {
+        return 0.0;
+    }
+    let mut sum = Vector3D::new(0.0, 0.0, 0.0);
+    for i in 0..vertices.len() {
+        let next = vertices[(i + 1) % vertices.len()];
+        sum = sum + vertices[i].cross(&next);
+    }
+    sum.magnitude() / 2.0
+}
+
+pub fn polygon_normal(vertices: &[Vector3D]) -> Vector3D {
+    let mut normal = Vector3D::new(0.0, 0.0, 0.0);
+    for i in 0..vertices.len() {
+        let current = vertices[i];
+        let next = vertices[(i + 1) % vertices.len()];
+        normal.x += (current.y - next.y) * (current.z + next.z);
+        normal.y += (current.z - next.z) * (current.x + next.x);
+        normal.z += (current.x - next.x) * (current.y + next.y);
+    }
+    if normal.magnitude() <= f64::EPSILON {
+        return Vector3D::new(0.0, 0.0, 0.0);
+    }
+    normal.normalize()
+}
+
+pub fn tetrahedron_volume(a: Vector3D, b: Vector3D, c: Vector3D, d: Vector3D) -> f64 {
+    (b - a).cross(&(c - a)).dot(&(d - a)) / 6.0
+}
+
 impl Vector3D {
+    pub fn abs(&self) -> Vector3D {
+        Vector3D::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    pub fn any_perpendicular(&self) -> Vector3D {
+        let a = self.abs();
+        let axis = if a.x <= a.y && a.x <= a.z {
+            Vector3D::new(1.0, 0.0, 0.0)
+        } else if a.y <= a.z {
+            Vector3D::new(0.0, 1.0, 0.0)
+        } else {
+            Vector3D::new(0.0, 0.0, 1.0)
+        };
+        self.cross(&axis)
+    }
+
+    /// The zero vector has no direction to scale along, so it is returned unchanged even when `min_len > 0`.
+    /// Swapped bounds are reordered and a NaN bound is ignored, so this never panics.
+    pub fn clamp_length(&self, min_len: f64, max_len: f64) -> Vector3D {
+        let length = self.magnitude();
+        if length <= f64::EPSILON {
+            return *self;
+        }
+        let (low, high) = if min_len > max_len { (max_len, min_len) } else { (min_len, max_len) };
+        *self * (length.max(low).min(high) / length)
+    }
+
+    pub fn closest_point_on_plane(&self, plane: &Plane) -> Vector3D {
+        let n = plane.get_normal();
+        *self - n * (plane.dot_vector(self) + plane.w) / n.dot(&n)
+    }
+
+    pub fn component_product(&self) -> f64 {
+        self.x * self.y * self.z
+    }
+
+    pub fn component_sum(&self) -> f64 {
+        self.x + self.y + self.z
+    }
+
     pub fn cross(&self, rhs: &Vector3D) -> Vector3D {
         Vector3D { x: self.y * rhs.z - self.z * rhs.y,
             y: self.z * rhs.x - self.x * rhs.z,
             z: self.x * rhs.y - self.y * rhs.x }
     }
 
+    pub fn decompose(&self, axis: &Vector3D) -> (Vector3D, Vector3D) {
+        let projection = *axis * (self.dot(axis) / axis.dot(axis));
+        (projection, *self - projection)
+    }
+
+    pub fn distance_to_plane(&self, plane: &Plane) -> f64 {
+        (plane.dot_vector(self) + plane.w).abs() / plane.get_normal().magnitude()
+    }
+
     pub fn dot(&self, rhs: &Vector3D) -> f64 {
         self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
     }
 
+    pub fn eq(&self, other: &Vector3D) -> (bool, bool, bool) {
+        (self.x == other.x, self.y == other.y, self.z == other.z)
+    }
+
+    pub fn from_angle_xy(radians: f64) -> Vector3D {
+        Vector3D::new(radians.cos(), radians.sin(), 0.0)
+    }
+
+    pub fn ge(&self, other: &Vector3D) -> (bool, bool, bool) {
+        (self.x >= other.x, self.y >= other.y, self.z >= other.z)
+    }
+
+    pub fn gt(&self, other: &Vector3D) -> (bool, bool, bool) {
+        (self.x > other.x, self.y > other.y, self.z > other.z)
+    }
+
+    /// Zero vectors are never antiparallel.
+    pub fn is_antiparallel(&self, other: &Vector3D, epsilon: f64) -> bool {
+        self.is_parallel(other, epsilon) && self.dot(other) < 0.0
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    /// Antiparallel vectors count as parallel; zero vectors are never parallel.
+    pub fn is_parallel(&self, other: &Vector3D, epsilon: f64) -> bool {
+        let magnitudes = self.magnitude() * other.magnitude();
+        magnitudes > 0.0 && self.cross(other).magnitude() <= epsilon * magnitudes
+    }
+
+    pub fn le(&self, other: &Vector3D) -> (bool, bool, bool) {
+        (self.x <= other.x, self.y <= other.y, self.z <= other.z)
+    }
+
+    pub fn linear_to_srgb(&self) -> Vector3D {
+        Vector3D::new(linear_channel_to_srgb(self.x),
+            linear_channel_to_srgb(self.y),
+            linear_channel_to_srgb(self.z))
+    }
+
+    pub fn lt(&self, other: &Vector3D) -> (bool, bool, bool) {
+        (self.x < other.x, self.y < other.y, self.z < other.z)
+    }
+
     pub fn magnitude(&self) -> f64 {
         let sum = f64::powi(self.x, 2) + f64::powi(self.y, 2) + f64::powi(self.z, 2);
         sum.sqrt()
     }
 
+    pub fn max_abs_axis(&self) -> usize {
+        self.max_component().0
+    }
+
+    pub fn max_component(&self) -> (usize, f64) {
+        let mut axis = 0;
+        for i in 1..3 {
+            if self[i].abs() > self[axis].abs() {
+                axis = i;
+            }
+        }
+        (axis, self[axis])
+    }
+
+    pub fn midpoint(&self, other: &Vector3D) -> Vector3D {
+        (*self + *other) * 0.5
+    }
+
     pub fn new(x: f64, y: f64, z: f64) -> Self {
         Self { x, y, z }
     }
@@ -36,12 +265,88 @@ impl Vector3D {
     }
 
     pub fn project(&self, rhs: &Vector3D) -> Vector3D {
-        *rhs * (self.dot(&rhs) / rhs.dot(&rhs))
+        *rhs * (self.dot(rhs) / rhs.dot(rhs))
+    }
+
+    /// Projects onto `axis`, which must be unit length.
+    pub fn project_onto_unit(&self, axis: &Vector3D) -> Vector3D {
+        *axis * self.dot(axis)
+    }
+
+    /// Zero components map to infinity with the sign of the zero.
+    pub fn recip(&self) -> Vector3D {
+        Vector3D::new(self.x.recip(), self.y.recip(), self.z.recip())
+    }
+
+    pub fn refract(&self, normal: &Vector3D, eta: f64) -> Option<Vector3D> {
+        let cos_i = -self.dot(normal);
+        let k = 1.0 - eta * eta * (1.0 - cos_i * cos_i);
+        if k < 0.0 {
+            None
+        } else {
+            Some(*self * eta + *normal * (eta * cos_i - k.sqrt()))
+        }
     }
 
     pub fn reject(&self, rhs: &Vector3D) -> Vector3D {
         *self - self.project(rhs)
     }
+
+    pub fn saturate(&self) -> Vector3D {
+        Vector3D::new(self.x.clamp(0.0, 1.0), self.y.clamp(0.0, 1.0), self.z.clamp(0.0, 1.0))
+    }
+
+    pub fn select(mask: (bool, bool, bool), if_true: Vector3D, if_false: Vector3D) -> Vector3D {
+        Vector3D::new(if mask.0 { if_true.x } else { if_false.x },
+            if mask.1 { if_true.y } else { if_false.y },
+            if mask.2 { if_true.z } else { if_false.z })
+    }
+
+    /// Follows `f64::signum`, so `0.0` maps to `1.0` and `-0.0` maps to `-1.0` rather than to zero.
+    pub fn signum(&self) -> Vector3D {
+        Vector3D::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    pub fn srgb_to_linear(&self) -> Vector3D {
+        Vector3D::new(srgb_channel_to_linear(self.x),
+            srgb_channel_to_linear(self.y),
+            srgb_channel_to_linear(self.z))
+    }
+
+    pub fn to_angle_xy(&self) -> f64 {
+        self.y.atan2(self.x)
+    }
+
+    pub fn total_cmp(&self, other: &Vector3D) -> std::cmp::Ordering {
+        self.x.total_cmp(&other.x)
+            .then(self.y.total_cmp(&other.y))
+            .then(self.z.total_cmp(&other.z))
+    }
+
+    pub fn wrap_angles_degrees(&self) -> Vector3D {
+        let wrap = |a: f64| 180.0 - (180.0 - a).rem_euclid(360.0);
+        Vector3D::new(wrap(self.x), wrap(self.y), wrap(self.z))
+    }
+
+    pub fn xzy(&self) -> Vector3D {
+        Vector3D::new(self.x, self.z, self.y)
+    }
+
+    pub fn yxz(&self) -> Vector3D {
+        Vector3D::new(self.y, self.x, self.z)
+    }
+
+    pub fn yzx(&self) -> Vector3D {
+        Vector3D::new(self.y, self.z, self.x)
+    }
+
+    pub fn zxy(&self) -> Vector3D {
+        Vector3D::new(self.z, self.x, self.y)
+    }
+
+    pub fn zyx(&self) -> Vector3D {
+        Vector3D::new(self.z, self.y, self.x)
+    }
 }
 
 impl Add<Self> for Vector3D {
@@ -64,6 +369,18 @@ impl From<Vector4D> for Vector3D {
     }
 }
 
+impl From<(f64, f64, f64)> for Vector3D {
+    fn from(t: (f64, f64, f64)) -> Self {
+        Vector3D::new(t.0, t.1, t.2)
+    }
+}
+
+impl From<Vector3D> for (f64, f64, f64) {
+    fn from(v: Vector3D) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
 impl Index<usize> for Vector3D {
     type Output = f64;
     fn index(&self, index: usize) -> &Self::Output {
@@ -121,6 +438,16 @@ impl Mul<Vector3D> for f64 {
     }
 }
 
+impl Mul<Matrix3D> for Vector3D {
+    type Output = Vector3D;
+    fn mul(self, rhs: Matrix3D) -> Self::Output {
+        Vector3D::new(
+            self.x * rhs[0][0] + self.y * rhs[1][0] + self.z * rhs[2][0],
+            self.x * rhs[0][1] + self.y * rhs[1][1] + self.z * rhs[2][1],
+            self.x * rhs[0][2] + self.y * rhs[1][2] + self.z * rhs[2][2])
+    }
+}
+
 impl Mul<Transform4D> for Vector3D {
     type Output = Vector3D;
     fn mul(self, rhs: Transform4D) -> Self::Output {
@@ -155,15 +482,56 @@ pub struct Vector4D {
 }
 
 impl Vector4D {
+    pub fn abs(&self) -> Vector4D {
+        Vector4D::new(self.x.abs(), self.y.abs(), self.z.abs(), self.w.abs())
+    }
+
+    pub fn as_plane_normalize(&self) -> Vector4D {
+        let magnitude = Vector3D::from(*self).magnitude();
+        *self / magnitude
+    }
+
+    pub fn clamp(&self, min: &Vector4D, max: &Vector4D) -> Vector4D {
+        self.max_components(min).min_components(max)
+    }
+
+    pub fn component_product(&self) -> f64 {
+        self.x * self.y * self.z * self.w
+    }
+
+    pub fn component_sum(&self) -> f64 {
+        self.x + self.y + self.z + self.w
+    }
+
     pub fn dot(&self, rhs: &Vector4D) -> f64 {
         self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
     }
 
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+    }
+
     pub fn magnitude(&self) -> f64 {
         let sum = f64::powi(self.x, 2) + f64::powi(self.y, 2) + f64::powi(self.z, 2) + f64::powi(self.w, 2);
         sum.sqrt()
     }
 
+    pub fn max_components(&self, other: &Vector4D) -> Vector4D {
+        Vector4D::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z), self.w.max(other.w))
+    }
+
+    pub fn midpoint(&self, other: &Vector4D) -> Vector4D {
+        (*self + *other) * 0.5
+    }
+
+    pub fn min_components(&self, other: &Vector4D) -> Vector4D {
+        Vector4D::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z), self.w.min(other.w))
+    }
+
     pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
         Self { x, y, z, w }
     }
@@ -174,12 +542,22 @@ impl Vector4D {
     }
 
     pub fn project(&self, rhs: &Vector4D) -> Vector4D {
-        *rhs * (self.dot(&rhs) / rhs.dot(&rhs))
+        *rhs * (self.dot(rhs) / rhs.dot(rhs))
+    }
+
+    /// Zero components map to infinity with the sign of the zero.
+    pub fn recip(&self) -> Vector4D {
+        Vector4D::new(self.x.recip(), self.y.recip(), self.z.recip(), self.w.recip())
     }
 
     pub fn reject(&self, rhs: &Vector4D) -> Vector4D {
         *self - self.project(rhs)
     }
+
+    pub fn saturate(&self) -> Vector4D {
+        Vector4D::new(self.x.clamp(0.0, 1.0), self.y.clamp(0.0, 1.0),
+            self.z.clamp(0.0, 1.0), self.w.clamp(0.0, 1.0))
+    }
 }
 
 impl Add<Self> for Vector4D {
@@ -189,6 +567,15 @@ impl Add<Self> for Vector4D {
     }
 }
 
+impl Display for Vector4D {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "({:.*}, {:.*}, {:.*}, {:.*})", p, self.x, p, self.y, p, self.z, p, self.w),
+            None => write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w),
+        }
+    }
+}
+
 impl Div<f64> for Vector4D {
     type Output = Self;
     fn div(self, rhs: f64) -> Self::Output {
@@ -196,6 +583,18 @@ impl Div<f64> for Vector4D {
     }
 }
 
+impl From<(f64, f64, f64, f64)> for Vector4D {
+    fn from(t: (f64, f64, f64, f64)) -> Self {
+        Vector4D::new(t.0, t.1, t.2, t.3)
+    }
+}
+
+impl From<Vector4D> for (f64, f64, f64, f64) {
+    fn from(v: Vector4D) -> Self {
+        (v.x, v.y, v.z, v.w)
+    }
+}
+
 impl Index<usize> for Vector4D {
     type Output = f64;
     fn index(&self, index: usize) -> &Self::Output {
@@ -237,6 +636,28 @@ impl Sub<Self> for Vector4D {
     }
 }
 
+#[cfg(test)]
+mod vector2d_tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn winding_order() {
+        let a = Vector2D::new(0.0, 0.0);
+        let b = Vector2D::new(1.0, 0.0);
+        let c = Vector2D::new(0.0, 1.0);
+        assert_approx_eq!(signed_area_2d(a, b, c), 0.5);
+        assert_eq!(winding(a, b, c), Winding::CounterClockwise);
+
+        assert_approx_eq!(signed_area_2d(a, c, b), -0.5);
+        assert_eq!(winding(a, c, b), Winding::Clockwise);
+
+        let d = Vector2D::new(2.0, 0.0);
+        assert_eq!(signed_area_2d(a, b, d), 0.0);
+        assert_eq!(winding(a, b, d), Winding::Collinear);
+    }
+}
+
 #[cfg(test)]
 mod vector3d_tests {
     use super::*;
@@ -262,6 +683,14 @@ mod vector3d_tests {
         assert_eq!(new_vector, 2.2 * vector);
     }
 
+    #[test]
+    fn matrix_multiplication() {
+        let m = Matrix3D::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let v = Vector3D::new(2.0, 3.0, 4.0);
+        assert_eq!(Vector3D::new(42.0, 51.0, 60.0), v * m);
+        assert_eq!(m.transpose() * v, v * m);
+    }
+
     #[test]
     fn transform_multiplication() {
         let t = Transform4D::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0);
@@ -372,6 +801,21 @@ mod vector3d_tests {
         assert_eq!(zero_vector, i.project(&j));
     }
 
+    #[test]
+    fn unit_projection() {
+        let vector1 = Vector3D::new(1.5, -2.5, 3.5);
+        let axes = [Vector3D::new(1.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            Vector3D::new(1.0, 1.0, 1.0).normalize()];
+        for axis in axes {
+            let expected = vector1.project(&axis);
+            let projected = vector1.project_onto_unit(&axis);
+            assert_approx_eq!(projected.x, expected.x);
+            assert_approx_eq!(projected.y, expected.y);
+            assert_approx_eq!(projected.z, expected.z);
+        }
+    }
+
     #[test]
     fn rejection() {
         use std::f64::consts::PI;
@@ -389,6 +833,345 @@ mod vector3d_tests {
         assert_eq!(i, i.reject(&j));
         assert_eq!(vector1, vector1.project(&i) + vector1.reject(&i));
     }
+
+    #[test]
+    fn swizzle() {
+        let v = Vector3D::new(1.0, 2.0, 3.0);
+        assert_eq!(v.xzy(), Vector3D::new(v.x, v.z, v.y));
+        assert_eq!(v.yxz(), Vector3D::new(v.y, v.x, v.z));
+        assert_eq!(v.yzx(), Vector3D::new(v.y, v.z, v.x));
+        assert_eq!(v.zxy(), Vector3D::new(v.z, v.x, v.y));
+        assert_eq!(v.zyx(), Vector3D::new(v.z, v.y, v.x));
+    }
+
+    #[test]
+    fn tuple_conversion() {
+        let t = (1.5, -2.0, 3.25);
+        let v = Vector3D::from(t);
+        assert_eq!(v, Vector3D::new(1.5, -2.0, 3.25));
+        let (x, y, z): (f64, f64, f64) = v.into();
+        assert_eq!((x, y, z), t);
+    }
+
+    #[test]
+    fn coplanarity() {
+        let a = Vector3D::new(0.0, 0.0, 1.0);
+        let b = Vector3D::new(1.0, 0.0, 1.0);
+        let c = Vector3D::new(0.0, 1.0, 1.0);
+        let d = Vector3D::new(3.0, -2.0, 1.0);
+        assert!(are_coplanar(a, b, c, d, 1e-12));
+
+        let lifted = Vector3D::new(3.0, -2.0, 1.5);
+        assert!(!are_coplanar(a, b, c, lifted, 1e-12));
+    }
+
+    #[test]
+    fn point_in_polygon() {
+        let quad = [
+            Vector3D::new(0.0, 0.0, 1.0),
+            Vector3D::new(2.0, 0.0, 1.0),
+            Vector3D::new(3.0, 2.0, 1.0),
+            Vector3D::new(0.0, 2.0, 1.0),
+        ];
+        let normal = Vector3D::new(0.0, 0.0, 1.0);
+        assert!(point_in_convex_polygon(Vector3D::new(1.0, 1.0, 1.0), &quad, normal));
+        assert!(!point_in_convex_polygon(Vector3D::new(2.9, 0.5, 1.0), &quad, normal));
+        assert!(point_in_convex_polygon(Vector3D::new(1.0, 0.0, 1.0), &quad, normal));
+    }
+
+    #[test]
+    fn polygon_areas() {
+        let square = [
+            Vector3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(1.0, 0.0, 0.0),
+            Vector3D::new(1.0, 1.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+        ];
+        assert_approx_eq!(polygon_area(&square), 1.0);
+
+        let triangle = [
+            Vector3D::new(1.0, 0.0, 2.0),
+            Vector3D::new(1.0, 3.0, 2.0),
+            Vector3D::new(1.0, 0.0, 6.0),
+        ];
+        assert_approx_eq!(polygon_area(&triangle), 6.0);
+
+        let degenerate = [Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(1.0, 2.0, 3.0)];
+        assert_eq!(polygon_area(&degenerate), 0.0);
+    }
+
+    #[test]
+    fn polygon_normals() {
+        let mut square = [
+            Vector3D::new(0.0, 0.0, 2.0),
+            Vector3D::new(1.0, 0.0, 2.0),
+            Vector3D::new(1.0, 1.0, 2.0),
+            Vector3D::new(0.0, 1.0, 2.0),
+        ];
+        for (r, e) in std::iter::zip(polygon_normal(&square), Vector3D::new(0.0, 0.0, 1.0)) {
+            assert_approx_eq!(r, e);
+        }
+
+        square.reverse();
+        for (r, e) in std::iter::zip(polygon_normal(&square), Vector3D::new(0.0, 0.0, -1.0)) {
+            assert_approx_eq!(r, e);
+        }
+    }
+
+    #[test]
+    fn tetrahedron() {
+        let a = Vector3D::new(0.0, 0.0, 0.0);
+        let b = Vector3D::new(1.0, 0.0, 0.0);
+        let c = Vector3D::new(0.0, 1.0, 0.0);
+        let d = Vector3D::new(0.0, 0.0, 1.0);
+        assert_approx_eq!(tetrahedron_volume(a, b, c, d), 1.0 / 6.0);
+        assert_approx_eq!(tetrahedron_volume(a, c, b, d), -1.0 / 6.0);
+    }
+
+    #[test]
+    fn any_perpendicular() {
+        for v in [Vector3D::new(1.0, 0.0, 0.0), Vector3D::new(0.0, -2.0, 0.0), Vector3D::new(0.0, 0.0, 3.0),
+            Vector3D::new(1.0, 2.0, 3.0), Vector3D::new(-0.3, 0.9, 1e-6)] {
+            let p = v.any_perpendicular();
+            assert!(p.magnitude() > 0.0);
+            assert_approx_eq!(p.dot(&v), 0.0);
+        }
+    }
+
+    #[test]
+    fn clamp_length() {
+        let v = Vector3D::new(0.0, 3.0, 4.0);
+        assert_approx_eq!(v.clamp_length(10.0, 20.0).magnitude(), 10.0);
+        assert_eq!(v.clamp_length(1.0, 6.0), v);
+        let clamped = v.clamp_length(1.0, 2.5);
+        assert_approx_eq!(clamped.y, 1.5);
+        assert_approx_eq!(clamped.z, 2.0);
+        let zero = Vector3D::new(0.0, 0.0, 0.0);
+        assert_eq!(zero.clamp_length(1.0, 2.0), zero);
+
+        assert_approx_eq!(v.clamp_length(6.0, 1.0).magnitude(), 5.0);
+        assert_approx_eq!(v.clamp_length(3.0, 2.0).magnitude(), 3.0);
+        assert_approx_eq!(v.clamp_length(f64::NAN, 2.0).magnitude(), 2.0);
+        assert_approx_eq!(v.clamp_length(7.0, f64::NAN).magnitude(), 7.0);
+    }
+
+    #[test]
+    fn decompose() {
+        let v = Vector3D::new(3.0, -1.0, 2.0);
+        let axis = Vector3D::new(1.0, 1.0, 0.5);
+        let (projection, rejection) = v.decompose(&axis);
+        for (r, e) in std::iter::zip(projection + rejection, v) {
+            assert_approx_eq!(r, e);
+        }
+        assert_approx_eq!(rejection.dot(&axis), 0.0);
+        assert_eq!(projection, v.project(&axis));
+    }
+
+    #[test]
+    fn plane_queries() {
+        let plane = Plane::new(0.0, 0.0, 2.0, -4.0);
+        let above = Vector3D::new(1.0, 2.0, 5.0);
+        let below = Vector3D::new(-1.0, 0.5, -1.0);
+        let on = Vector3D::new(3.0, -3.0, 2.0);
+        assert_approx_eq!(above.distance_to_plane(&plane), 3.0);
+        assert_approx_eq!(below.distance_to_plane(&plane), 3.0);
+        assert_approx_eq!(on.distance_to_plane(&plane), 0.0);
+        assert_eq!(above.closest_point_on_plane(&plane), Vector3D::new(1.0, 2.0, 2.0));
+        assert_eq!(below.closest_point_on_plane(&plane), Vector3D::new(-1.0, 0.5, 2.0));
+        assert_eq!(on.closest_point_on_plane(&plane), on);
+    }
+
+    #[test]
+    fn abs_and_signum() {
+        let v = Vector3D::new(2.5, -3.0, 0.0);
+        assert_eq!(v.abs(), Vector3D::new(2.5, 3.0, 0.0));
+        assert_eq!(v.signum(), Vector3D::new(1.0, -1.0, 1.0));
+        assert_eq!(Vector3D::new(-0.0, 0.0, -7.0).signum(), Vector3D::new(-1.0, 1.0, -1.0));
+    }
+
+    #[test]
+    fn wrap_angles_degrees() {
+        let v = Vector3D::new(190.0, -190.0, 360.0).wrap_angles_degrees();
+        assert_approx_eq!(v.x, -170.0);
+        assert_approx_eq!(v.y, 170.0);
+        assert_approx_eq!(v.z, 0.0);
+
+        let v = Vector3D::new(540.0, 180.0, -180.0).wrap_angles_degrees();
+        assert_approx_eq!(v.x, 180.0);
+        assert_approx_eq!(v.y, 180.0);
+        assert_approx_eq!(v.z, 180.0);
+    }
+
+    #[test]
+    fn finite_and_nan() {
+        let v = Vector3D::new(1.0, 2.0, 3.0);
+        assert!(v.is_finite());
+        assert!(!v.is_nan());
+
+        let v = Vector3D::new(1.0, f64::INFINITY, 3.0);
+        assert!(!v.is_finite());
+        assert!(!v.is_nan());
+
+        let v = Vector3D::new(1.0, 2.0, f64::NAN);
+        assert!(!v.is_finite());
+        assert!(v.is_nan());
+    }
+
+    #[test]
+    fn angle_xy() {
+        use std::f64::consts::PI;
+        let cardinals = [(0.0, Vector3D::new(1.0, 0.0, 0.0)),
+            (PI / 2.0, Vector3D::new(0.0, 1.0, 0.0)),
+            (PI, Vector3D::new(-1.0, 0.0, 0.0)),
+            (-PI / 2.0, Vector3D::new(0.0, -1.0, 0.0))];
+        for (angle, expected) in cardinals {
+            let v = Vector3D::from_angle_xy(angle);
+            assert_approx_eq!(v.x, expected.x);
+            assert_approx_eq!(v.y, expected.y);
+            assert_eq!(v.z, 0.0);
+            assert_approx_eq!(v.to_angle_xy(), angle);
+        }
+
+        for angle in [0.3, 1.7, -2.9] {
+            assert_approx_eq!(Vector3D::from_angle_xy(angle).to_angle_xy(), angle);
+        }
+    }
+
+    #[test]
+    fn parallel() {
+        let a = Vector3D::new(1.0, 2.0, 3.0);
+        let b = Vector3D::new(2.0, 4.0, 6.0);
+        assert!(a.is_parallel(&b, 1e-12));
+        assert!(!a.is_antiparallel(&b, 1e-12));
+
+        let c = -b;
+        assert!(a.is_parallel(&c, 1e-12));
+        assert!(a.is_antiparallel(&c, 1e-12));
+
+        let d = Vector3D::new(3.0, 0.0, -1.0);
+        assert!(!a.is_parallel(&d, 1e-12));
+        assert!(!a.is_antiparallel(&d, 1e-12));
+
+        let zero = Vector3D::new(0.0, 0.0, 0.0);
+        assert!(!a.is_parallel(&zero, 1e-12));
+        assert!(!zero.is_antiparallel(&a, 1e-12));
+    }
+
+    #[test]
+    fn midpoint() {
+        let a = Vector3D::new(1.0, -2.0, 3.0);
+        let b = Vector3D::new(4.0, 6.0, -1.0);
+        let m = a.midpoint(&b);
+        assert_eq!(m, Vector3D::new(2.5, 2.0, 1.0));
+        assert_approx_eq!((m - a).magnitude(), (m - b).magnitude());
+    }
+
+    #[test]
+    fn component_reduction() {
+        let v = Vector3D::new(2.0, 3.0, 4.0);
+        assert_eq!(v.component_sum(), 2.0 + 3.0 + 4.0);
+        assert_eq!(v.component_product(), 2.0 * 3.0 * 4.0);
+    }
+
+    #[test]
+    fn max_component() {
+        let v = Vector3D::new(-5.0, 2.0, 3.0);
+        assert_eq!(v.max_abs_axis(), 0);
+        assert_eq!(v.max_component(), (0, -5.0));
+
+        let v = Vector3D::new(0.1, -0.9, 0.5);
+        assert_eq!(v.max_abs_axis(), 1);
+        assert_eq!(v.max_component(), (1, -0.9));
+
+        let v = Vector3D::new(1.0, 2.0, 3.0);
+        assert_eq!(v.max_abs_axis(), 2);
+        assert_eq!(v.max_component(), (2, 3.0));
+    }
+
+    #[test]
+    fn component_comparison() {
+        let a = Vector3D::new(1.0, 5.0, 3.0);
+        let b = Vector3D::new(2.0, 4.0, 3.0);
+        assert_eq!(a.lt(&b), (true, false, false));
+        assert_eq!(a.gt(&b), (false, true, false));
+        assert_eq!(a.le(&b), (true, false, true));
+        assert_eq!(a.ge(&b), (false, true, true));
+        assert_eq!(Vector3D::eq(&a, &b), (false, false, true));
+    }
+
+    #[test]
+    fn reciprocal() {
+        let v = Vector3D::new(2.0, -4.0, 0.0);
+        assert_eq!(v.recip(), Vector3D::new(0.5, -0.25, f64::INFINITY));
+        assert_eq!(Vector3D::new(-0.0, 1.0, 1.0).recip().x, f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn refraction() {
+        let normal = Vector3D::new(0.0, 1.0, 0.0);
+        let incident = Vector3D::new(1.0, -1.0, 0.0).normalize();
+        let eta = 1.0 / 1.5;
+        let refracted = incident.refract(&normal, eta).unwrap();
+        assert_approx_eq!(refracted.magnitude(), 1.0);
+        assert!(refracted.y < 0.0);
+        assert!(refracted.x < incident.x);
+        let sin_i = incident.cross(&normal).magnitude();
+        let sin_t = refracted.cross(&normal).magnitude();
+        assert_approx_eq!(sin_t, eta * sin_i);
+
+        let grazing = Vector3D::new(3.0_f64.sqrt(), -1.0, 0.0).normalize();
+        assert_eq!(None, grazing.refract(&normal, 1.5));
+    }
+
+    #[test]
+    fn total_ordering() {
+        let a = Vector3D::new(-1.0, 0.0, 0.0);
+        let b = Vector3D::new(1.0, f64::NAN, 0.0);
+        let c = Vector3D::new(1.0, 2.0, 3.0);
+        let d = Vector3D::new(1.0, 2.0, f64::NAN);
+        let e = Vector3D::new(f64::NAN, 0.0, 0.0);
+
+        let mut v1: Vec<Vector3D> = [e, c, a, d, b].to_vec();
+        let mut v2: Vec<Vector3D> = [b, d, e, a, c].to_vec();
+        v1.sort_by(|l, r| l.total_cmp(r));
+        v2.sort_by(|l, r| l.total_cmp(r));
+        let expected = [a, c, d, b, e];
+        for ((l, r), x) in v1.iter().zip(v2.iter()).zip(expected.iter()) {
+            assert_eq!(l.total_cmp(x), std::cmp::Ordering::Equal);
+            assert_eq!(r.total_cmp(x), std::cmp::Ordering::Equal);
+        }
+    }
+
+    #[test]
+    fn select() {
+        let a = Vector3D::new(1.0, 5.0, -3.0);
+        let b = Vector3D::new(2.0, 4.0, 3.0);
+        assert_eq!(Vector3D::select((true, false, true), a, b), Vector3D::new(1.0, 4.0, -3.0));
+
+        let max_components = Vector3D::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z));
+        assert_eq!(Vector3D::select(a.gt(&b), a, b), max_components);
+    }
+
+    #[test]
+    fn saturate() {
+        let v = Vector3D::new(-0.5, 0.25, 1.5);
+        assert_eq!(v.saturate(), Vector3D::new(0.0, 0.25, 1.0));
+    }
+
+    #[test]
+    fn srgb_conversion() {
+        let v = Vector3D::new(0.5, 0.5, 0.5);
+        let linear = v.srgb_to_linear();
+        assert_approx_eq!(linear.x, 0.214041140);
+        assert_approx_eq!(linear.y, 0.214041140);
+        assert_approx_eq!(linear.z, 0.214041140);
+
+        let v = Vector3D::new(0.02, 0.5, 0.9);
+        let round_trip = v.srgb_to_linear().linear_to_srgb();
+        assert_approx_eq!(round_trip.x, v.x);
+        assert_approx_eq!(round_trip.y, v.y);
+        assert_approx_eq!(round_trip.z, v.z);
+    }
 }
 
 #[cfg(test)]
@@ -396,6 +1179,19 @@ mod vector4d_tests {
     use super::*;
     use assert_approx_eq::assert_approx_eq;
 
+    #[test]
+    fn component_min_max_clamp_abs() {
+        let a = Vector4D::new(1.0, -2.0, 3.0, -4.0);
+        let b = Vector4D::new(-1.0, 2.0, 5.0, -6.0);
+        assert_eq!(a.min_components(&b), Vector4D::new(-1.0, -2.0, 3.0, -6.0));
+        assert_eq!(a.max_components(&b), Vector4D::new(1.0, 2.0, 5.0, -4.0));
+        assert_eq!(a.abs(), Vector4D::new(1.0, 2.0, 3.0, 4.0));
+
+        let min = Vector4D::new(0.0, 0.0, 0.0, -5.0);
+        let max = Vector4D::new(0.5, 1.0, 2.0, 5.0);
+        assert_eq!(a.clamp(&min, &max), Vector4D::new(0.5, 0.0, 2.0, -4.0));
+    }
+
     #[test]
     fn index_operator() {
         let vector = Vector4D::new(1.1, 2.2, 3.3, 4.4);
@@ -448,6 +1244,9 @@ mod vector4d_tests {
         let vector = Vector4D::new(1.1, 1.1, 1.1, 1.1);
         let normalized_vector = vector.normalize();
         assert_eq!(normalized_vector.magnitude(), 1.0);
+
+        let vector = Vector4D::new(3.0, -0.5, 7.25, 2.0);
+        assert_approx_eq!(vector.normalize().magnitude(), 1.0);
     }
 
     #[test]
@@ -469,7 +1268,17 @@ mod vector4d_tests {
         assert_approx_eq!(vector3.x, 1.1);
         assert_approx_eq!(vector3.y, 1.1);
         assert_approx_eq!(vector3.z, 1.1);
-        assert_approx_eq!(vector3.z, 1.1);
+        assert_approx_eq!(vector3.w, 1.1);
+    }
+
+    #[test]
+    fn componentwise_arithmetic() {
+        let a = Vector4D::new(1.0, 2.0, 3.0, 4.0);
+        let b = Vector4D::new(10.0, 20.0, 30.0, 40.0);
+        assert_eq!(a + b, Vector4D::new(11.0, 22.0, 33.0, 44.0));
+        assert_eq!(b - a, Vector4D::new(9.0, 18.0, 27.0, 36.0));
+        assert_eq!(-a, Vector4D::new(-1.0, -2.0, -3.0, -4.0));
+        assert_eq!(a - b, -(b - a));
     }
 
     #[test]
@@ -481,6 +1290,10 @@ mod vector4d_tests {
 
         let squared_magnitude = vector1.dot(&vector1);
         assert_approx_eq!(squared_magnitude, f64::powi(vector1.magnitude(), 2));
+
+        let a = Vector4D::new(1.0, 2.0, -1.0, 3.0);
+        let b = Vector4D::new(2.0, -1.0, 3.0, 1.0);
+        assert_eq!(a.dot(&b), 0.0);
     }
 
     #[test]
@@ -520,4 +1333,74 @@ mod vector4d_tests {
         assert_eq!(vector1, vector1.project(&i) + vector1.reject(&i));
         assert_eq!(l, l.reject(&i));
     }
+
+    #[test]
+    fn display() {
+        let vector = Vector4D::new(1.0, 2.5, -3.125, 4.0);
+        assert_eq!(format!("{}", vector), "(1, 2.5, -3.125, 4)");
+        assert_eq!(format!("{:.2}", vector), "(1.00, 2.50, -3.12, 4.00)");
+    }
+
+    #[test]
+    fn tuple_conversion() {
+        let t = (1.5, -2.0, 3.25, 4.0);
+        let v = Vector4D::from(t);
+        assert_eq!(v, Vector4D::new(1.5, -2.0, 3.25, 4.0));
+        let (x, y, z, w): (f64, f64, f64, f64) = v.into();
+        assert_eq!((x, y, z, w), t);
+    }
+
+    #[test]
+    fn finite_and_nan() {
+        let v = Vector4D::new(1.0, 2.0, 3.0, 4.0);
+        assert!(v.is_finite());
+        assert!(!v.is_nan());
+
+        let v = Vector4D::new(1.0, 2.0, 3.0, f64::NEG_INFINITY);
+        assert!(!v.is_finite());
+        assert!(!v.is_nan());
+
+        let v = Vector4D::new(f64::NAN, 2.0, 3.0, 4.0);
+        assert!(!v.is_finite());
+        assert!(v.is_nan());
+    }
+
+    #[test]
+    fn midpoint() {
+        let a = Vector4D::new(1.0, -2.0, 3.0, 0.0);
+        let b = Vector4D::new(4.0, 6.0, -1.0, 2.0);
+        let m = a.midpoint(&b);
+        assert_eq!(m, Vector4D::new(2.5, 2.0, 1.0, 1.0));
+        assert_approx_eq!((m - a).magnitude(), (m - b).magnitude());
+    }
+
+    #[test]
+    fn component_reduction() {
+        let v = Vector4D::new(2.0, 3.0, 4.0, 0.5);
+        assert_eq!(v.component_sum(), 2.0 + 3.0 + 4.0 + 0.5);
+        assert_eq!(v.component_product(), 2.0 * 3.0 * 4.0 * 0.5);
+    }
+
+    #[test]
+    fn saturate() {
+        let v = Vector4D::new(-0.5, 0.25, 1.5, 2.0);
+        assert_eq!(v.saturate(), Vector4D::new(0.0, 0.25, 1.0, 1.0));
+    }
+
+    #[test]
+    fn reciprocal() {
+        let v = Vector4D::new(2.0, -4.0, 0.0, 0.5);
+        assert_eq!(v.recip(), Vector4D::new(0.5, -0.25, f64::INFINITY, 2.0));
+    }
+
+    #[test]
+    fn plane_normalize() {
+        let plane = Vector4D::new(0.0, 3.0, 4.0, -10.0);
+        let normalized = plane.as_plane_normalize();
+        assert_approx_eq!(Vector3D::from(normalized).magnitude(), 1.0);
+        assert_approx_eq!(normalized.w, -2.0);
+
+        let p = Vector4D::new(1.0, 0.0, 5.0, 1.0);
+        assert_approx_eq!(normalized.dot(&p), 2.0);
+    }
 }
\ No newline at end of file